@@ -1,5 +1,8 @@
 use std::error::Error;
-use waka::{AllTimesSinceTodayOptions, CommitOptions, SummariesOptions, WakaTimeClientBuilder};
+use waka::{
+    AllTimesSinceTodayOptions, CommitOptions, DurationsOptions, SummariesOptions,
+    WakaTimeClientBuilder,
+};
 
 #[tokio::main]
 pub async fn main() -> Result<(), Box<dyn Error>> {
@@ -15,6 +18,11 @@ pub async fn main() -> Result<(), Box<dyn Error>> {
         .await?;
     println!("{summary:?}");
 
+    let durations = client
+        .durations("2023-01-02", DurationsOptions::default())
+        .await?;
+    println!("{durations:?}");
+
     let all_time_since_today = client
         .all_time_since_today(AllTimesSinceTodayOptions::default())
         .await?;
@@ -1,30 +1,172 @@
+use crate::model::heartbeats::BulkHeartbeatResult;
+use crate::oauth::Scope;
+use crate::transport::TransportError;
 use crate::ErrorsResponse;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::time::Duration;
 
 #[derive(Debug)]
 pub enum ApiError {
-    Unspecified(u16, Option<ErrorsResponse>),
-    InvalidFormat(reqwest::Error),
-    Unauthorized(Option<ErrorsResponse>),
-    ServerError(reqwest::Error),
+    /// The server responded with an unexpected status code.
+    Unspecified(u16, ErrorContext),
+    /// The response could not be deserialized.
+    InvalidFormat {
+        context: ErrorContext,
+        /// The reason the response could not be deserialized.
+        source: serde_json::Error,
+    },
+    /// The request was invalid, e.g. because a parameter failed validation (400).
+    BadRequest {
+        context: ErrorContext,
+        /// The raw response body.
+        body: String,
+    },
+    /// The API key or token is missing or invalid (401).
+    Unauthorized(ErrorContext),
+    /// The API key or token is not allowed to access the resource (403).
+    Forbidden(ErrorContext),
+    /// The resource does not exist (404).
+    NotFound(ErrorContext),
+    /// The server rejected the request because too many requests were sent (429).
+    RateLimited {
+        context: ErrorContext,
+        /// The time to wait before sending another request, if the server specified it.
+        retry_after: Option<Duration>,
+    },
+    /// The server failed to process the request (5xx).
+    ServerError(u16, ErrorContext),
+    /// The request could not be sent or the response could not be received.
+    RequestFailed(TransportError),
+    /// The server accepted the request but is still calculating the data.
+    NotUpToDate {
+        /// A number between 0 and 100 where 100 means the data is up to date.
+        percent_calculated: Option<u8>,
+    },
+    /// Some heartbeats of a bulk request were rejected; contains the results of all heartbeats.
+    PartialFailure(Vec<BulkHeartbeatResult>),
+    /// The OAuth access token does not grant the scope required by the endpoint;
+    /// no request was sent.
+    MissingScope(Scope),
+    /// An id or name passed as a segment of the path is `.` or `..`, which the URL would
+    /// resolve to another endpoint; no request was sent.
+    InvalidPathSegment(String),
+}
+
+/// Describes the request that failed.
+#[derive(Debug, Clone)]
+pub struct ErrorContext {
+    /// The name of the endpoint, e.g. `summaries`.
+    pub endpoint: &'static str,
+    /// The URL of the request.
+    pub url: String,
+    /// The errors reported by the server, if any.
+    pub errors: Option<ErrorsResponse>,
+}
+
+impl ApiError {
+    /// Gets the context of the failed request, if the server responded with an error status
+    /// or a body that could not be deserialized.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            ApiError::Unspecified(_, context)
+            | ApiError::InvalidFormat { context, .. }
+            | ApiError::BadRequest { context, .. }
+            | ApiError::Unauthorized(context)
+            | ApiError::Forbidden(context)
+            | ApiError::NotFound(context)
+            | ApiError::RateLimited { context, .. }
+            | ApiError::ServerError(_, context) => Some(context),
+            _ => None,
+        }
+    }
 }
 
 impl Display for ApiError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            ApiError::Unspecified(code, _) => write!(f, "An unspecified error occurred: {code}"),
-            ApiError::Unauthorized(_) => write!(f, "Authorization failed"),
-            ApiError::ServerError(e) => write!(f, "The call failed: {e}"),
-            ApiError::InvalidFormat(e) => write!(f, "The API returned an unexpected format: {e}"),
+            ApiError::Unspecified(code, context) => {
+                write!(f, "An unspecified error occurred: {code} in {context}")
+            }
+            ApiError::InvalidFormat { context, source } => {
+                write!(
+                    f,
+                    "The API returned an unexpected format in {context}: {source}"
+                )
+            }
+            ApiError::BadRequest { context, .. } => write!(f, "Bad request in {context}"),
+            ApiError::Unauthorized(context) => write!(f, "Authorization failed in {context}"),
+            ApiError::Forbidden(context) => write!(f, "Access denied in {context}"),
+            ApiError::NotFound(context) => write!(f, "Not found in {context}"),
+            ApiError::RateLimited {
+                context,
+                retry_after: Some(retry_after),
+            } => write!(
+                f,
+                "Too many requests in {context}; retry after {seconds} seconds",
+                seconds = retry_after.as_secs()
+            ),
+            ApiError::RateLimited {
+                context,
+                retry_after: None,
+            } => write!(f, "Too many requests in {context}"),
+            ApiError::ServerError(code, context) => {
+                write!(f, "The server failed with {code} in {context}")
+            }
+            ApiError::RequestFailed(e) => write!(f, "The call failed: {e}"),
+            ApiError::NotUpToDate {
+                percent_calculated: Some(percent),
+            } => write!(f, "The data is not up to date yet ({percent}% calculated)"),
+            ApiError::NotUpToDate {
+                percent_calculated: None,
+            } => write!(f, "The data is not up to date yet"),
+            ApiError::PartialFailure(results) => {
+                let failed = results.iter().filter(|r| !r.is_success()).count();
+                write!(
+                    f,
+                    "{failed} of {total} heartbeats were rejected",
+                    total = results.len()
+                )
+            }
+            ApiError::MissingScope(scope) => {
+                write!(f, "The access token does not grant the {scope} scope")
+            }
+            ApiError::InvalidPathSegment(segment) => {
+                write!(f, "`{segment}` cannot be used as an id or name in the path")
+            }
+        }
+    }
+}
+
+impl Display for ErrorContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{endpoint} ({url})",
+            endpoint = self.endpoint,
+            url = self.url
+        )?;
+        match &self.errors {
+            Some(errors) if !errors.errors.is_empty() => {
+                write!(f, ": {errors}", errors = errors.errors.join("; "))
+            }
+            _ => Ok(()),
         }
     }
 }
 
-impl Error for ApiError {}
+impl Error for ApiError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ApiError::InvalidFormat { source, .. } => Some(source),
+            ApiError::RequestFailed(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
-impl From<reqwest::Error> for ApiError {
-    fn from(value: reqwest::Error) -> Self {
-        Self::ServerError(value)
+impl From<TransportError> for ApiError {
+    fn from(value: TransportError) -> Self {
+        Self::RequestFailed(value)
     }
 }
@@ -0,0 +1,550 @@
+//! A synchronous client for the WakaTime API.
+//!
+//! The [`WakaTimeClient`] in this module provides the same endpoints and models as the
+//! asynchronous [`crate::WakaTimeClient`], but blocks the current thread on every call.
+//! Create it using [`WakaTimeClientBuilder::build_blocking`](crate::WakaTimeClientBuilder::build_blocking).
+//!
+//! ```no_run
+//! use waka::{SummariesOptions, WakaTimeClientBuilder};
+//!
+//! # fn test() -> Result<(), Box<dyn std::error::Error>> {
+//! let api_key = std::env::var("WAKATIME_API_KEY")?;
+//! let client = WakaTimeClientBuilder::new_with_api_key(api_key).build_blocking()?;
+//!
+//! let summary = client.summaries("2023-01-01", "2023-01-08", SummariesOptions::default())?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Requests are sent using [`ReqwestTransport`] unless a custom [`Transport`] is provided using
+//! [`WakaTimeClientBuilder::with_blocking_transport`](crate::WakaTimeClientBuilder::with_blocking_transport).
+
+use crate::endpoint::{self, Endpoint, Outcome};
+use crate::model::Paginated;
+use crate::oauth::Scope;
+use crate::polling::Poll;
+use crate::transport::{reqwest_headers, reqwest_method, Request, Response, TransportError};
+#[cfg(feature = "time")]
+use crate::DateRange;
+use crate::{
+    model, AllTimesSinceTodayOptions, ApiDate, ApiError, CommitOptions, CommitsOptions,
+    DurationsOptions, LeadersOptions, PaginationOptions, PaginationState, PollingPolicy,
+    ProjectsOptions, RetryPolicy, StatsOptions, StatsRange, SummariesOptions, SummariesRange,
+};
+use std::time::Duration;
+
+/// Sends requests to the API, blocking the current thread.
+///
+/// This is the synchronous counterpart of [`crate::transport::Transport`].
+pub trait Transport: Send + Sync {
+    /// Sends the request, returning the response regardless of its status code.
+    fn send(&self, request: Request) -> Result<Response, TransportError>;
+
+    /// Waits for the given time, e.g. before retrying a request or polling an endpoint
+    /// whose data is not up to date. By default, the current thread sleeps.
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+/// The default [`Transport`], based on [`reqwest::blocking`].
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::blocking::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new() -> Result<Self, TransportError> {
+        let client = reqwest::blocking::Client::builder()
+            .build()
+            .map_err(TransportError::from_reqwest)?;
+        Ok(Self { client })
+    }
+
+    /// Uses a preconfigured client, e.g. one with a proxy or custom timeouts.
+    pub fn from_client(client: reqwest::blocking::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: Request) -> Result<Response, TransportError> {
+        let mut builder = self
+            .client
+            .request(reqwest_method(request.method), &request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let response = builder.send().map_err(TransportError::from_reqwest)?;
+        let status = response.status().as_u16();
+        let headers = reqwest_headers(response.headers());
+        let body = response.bytes().map_err(TransportError::from_reqwest)?;
+
+        Ok(Response {
+            status,
+            headers,
+            body: body.to_vec(),
+        })
+    }
+}
+
+/// A synchronous client for accessing the WakaTime API.
+pub struct WakaTimeClient {
+    /// The transport sending the requests.
+    pub(crate) transport: Box<dyn Transport>,
+    /// The headers sent with every request, such as the authorization.
+    pub(crate) headers: Vec<(String, String)>,
+    /// The user to use, percent-encoded for use in the path.
+    pub(crate) user: String,
+    /// The base URL of the API, always ending in a slash.
+    pub(crate) base_url: String,
+    /// The policy for polling data that is not up to date, if enabled.
+    pub(crate) polling: Option<PollingPolicy>,
+    /// The policy for retrying failed requests.
+    pub(crate) retry_policy: RetryPolicy,
+    /// The scopes granted to the OAuth access token, if known.
+    pub(crate) scopes: Option<Vec<Scope>>,
+}
+
+impl WakaTimeClient {
+    /// ## Documentation
+    /// * [All Time Since Today](https://wakatime.com/developers#all_time_since_today)
+    pub fn all_time_since_today(
+        &self,
+        options: AllTimesSinceTodayOptions,
+    ) -> Result<model::AllTimeSinceToday, ApiError> {
+        self.execute(endpoint::all_time_since_today(&self.user, options))
+    }
+
+    /// ## Documentation
+    /// * [Commits](https://wakatime.com/developers#commits)
+    pub fn commit(
+        &self,
+        project: &str,
+        hash: &str,
+        options: CommitOptions,
+    ) -> Result<model::Commits, ApiError> {
+        self.execute(endpoint::commit(&self.user, project, hash, options)?)
+    }
+
+    /// Fetches a single page of the commits of a project.
+    ///
+    /// ## Documentation
+    /// * [Commits](https://wakatime.com/developers#commits)
+    pub fn commits(
+        &self,
+        project: &str,
+        options: CommitsOptions,
+    ) -> Result<model::commit::CommitsPage, ApiError> {
+        self.execute(endpoint::commits(&self.user, project, options)?)
+    }
+
+    /// Fetches the commits of a project, following all pages.
+    ///
+    /// The `page` of the `options` is ignored; use [`PaginationOptions`] to limit the pages fetched.
+    ///
+    /// ## Documentation
+    /// * [Commits](https://wakatime.com/developers#commits)
+    pub fn commits_iter<'a>(
+        &'a self,
+        project: &'a str,
+        options: CommitsOptions<'a>,
+        pagination: PaginationOptions,
+    ) -> impl Iterator<Item = Result<model::commit::Commit, ApiError>> + 'a {
+        self.paginate(pagination, move |client, page| {
+            let options = CommitsOptions {
+                page: Some(page),
+                ..options.clone()
+            };
+            client.commits(project, options)
+        })
+    }
+
+    /// ## Documentation
+    /// * [Durations](https://wakatime.com/developers#durations)
+    pub fn durations<D: ApiDate>(
+        &self,
+        date: D,
+        options: DurationsOptions,
+    ) -> Result<model::Durations, ApiError> {
+        self.execute(endpoint::durations(&self.user, date, options))
+    }
+
+    /// ## Documentation
+    /// * [Goals](https://wakatime.com/developers#goals)
+    pub fn goals(&self) -> Result<model::Goals, ApiError> {
+        self.execute(endpoint::goals(&self.user))
+    }
+
+    /// ## Documentation
+    /// * [Goal](https://wakatime.com/developers#goal)
+    pub fn goal(&self, id: &str) -> Result<model::goals::Goal, ApiError> {
+        self.execute(endpoint::goal(&self.user, id)?)
+    }
+
+    /// ## Documentation
+    /// * [Heartbeats](https://wakatime.com/developers#heartbeats)
+    pub fn heartbeats<D: ApiDate>(&self, date: D) -> Result<model::Heartbeats, ApiError> {
+        self.execute(endpoint::heartbeats(&self.user, date))
+    }
+
+    /// Sends a single heartbeat for the current user.
+    ///
+    /// ## Documentation
+    /// * [Heartbeats](https://wakatime.com/developers#heartbeats)
+    pub fn send_heartbeat(
+        &self,
+        heartbeat: &model::heartbeats::NewHeartbeat,
+    ) -> Result<model::heartbeats::CreatedHeartbeat, ApiError> {
+        self.execute(endpoint::send_heartbeat(heartbeat))
+    }
+
+    /// Sends multiple heartbeats for the current user in a single request.
+    ///
+    /// Returns the result of every heartbeat in the order they were sent. If the server
+    /// rejected any of them, [`ApiError::PartialFailure`] is returned instead.
+    ///
+    /// ## Documentation
+    /// * [Heartbeats](https://wakatime.com/developers#heartbeats)
+    pub fn send_heartbeats_bulk(
+        &self,
+        heartbeats: &[model::heartbeats::NewHeartbeat],
+    ) -> Result<Vec<model::heartbeats::BulkHeartbeatResult>, ApiError> {
+        self.execute(endpoint::send_heartbeats_bulk(heartbeats))
+    }
+
+    /// Fetches a single page of the public leaderboard of the last 7 days.
+    ///
+    /// ## Documentation
+    /// * [Leaders](https://wakatime.com/developers#leaders)
+    pub fn leaders(&self, options: LeadersOptions) -> Result<model::leaders::Leaders, ApiError> {
+        self.execute(endpoint::leaders(options))
+    }
+
+    /// Fetches the public leaderboard of the last 7 days, following all pages.
+    ///
+    /// The `page` of the `options` is ignored; use [`PaginationOptions`] to limit the pages fetched.
+    ///
+    /// ## Documentation
+    /// * [Leaders](https://wakatime.com/developers#leaders)
+    pub fn leaders_iter<'a>(
+        &'a self,
+        options: LeadersOptions<'a>,
+        pagination: PaginationOptions,
+    ) -> impl Iterator<Item = Result<model::leaders::Leader, ApiError>> + 'a {
+        self.paginate(pagination, move |client, page| {
+            let options = LeadersOptions {
+                page: Some(page),
+                ..options.clone()
+            };
+            client.leaders(options)
+        })
+    }
+
+    /// Lists the machines the user sent heartbeats from, e.g. to resolve the
+    /// `machine_name_id` of the summaries.
+    ///
+    /// ## Documentation
+    /// * [Machine Names](https://wakatime.com/developers#machine_names)
+    pub fn machine_names(&self) -> Result<Vec<model::machine_names::MachineName>, ApiError> {
+        self.execute(endpoint::machine_names(&self.user))
+    }
+
+    /// Lists the organizations the user belongs to.
+    ///
+    /// ## Documentation
+    /// * [Orgs](https://wakatime.com/developers#orgs)
+    pub fn orgs(&self) -> Result<model::orgs::Orgs, ApiError> {
+        self.execute(endpoint::orgs(&self.user))
+    }
+
+    /// Lists the dashboards of an organization visible to the user.
+    ///
+    /// ## Documentation
+    /// * [Org Dashboards](https://wakatime.com/developers#org_dashboards)
+    pub fn org_dashboards(&self, org: &str) -> Result<model::orgs::OrgDashboards, ApiError> {
+        self.execute(endpoint::org_dashboards(&self.user, org)?)
+    }
+
+    /// Fetches the durations of a member of an organization's dashboard for a single day.
+    ///
+    /// ## Documentation
+    /// * [Org Dashboard Member Durations](https://wakatime.com/developers#org_dashboard_member_durations)
+    pub fn org_dashboard_member_durations<D: ApiDate>(
+        &self,
+        org: &str,
+        dashboard: &str,
+        member: &str,
+        date: D,
+        options: DurationsOptions,
+    ) -> Result<model::Durations, ApiError> {
+        self.execute(endpoint::org_dashboard_member_durations(
+            &self.user, org, dashboard, member, date, options,
+        )?)
+    }
+
+    /// Fetches the summaries of a member of an organization's dashboard for every day in the given range.
+    ///
+    /// ## Documentation
+    /// * [Org Dashboard Member Summaries](https://wakatime.com/developers#org_dashboard_member_summaries)
+    pub fn org_dashboard_member_summaries<D: ApiDate>(
+        &self,
+        org: &str,
+        dashboard: &str,
+        member: &str,
+        start: D,
+        end: D,
+        options: SummariesOptions,
+    ) -> Result<model::Summaries, ApiError> {
+        self.execute(endpoint::org_dashboard_member_summaries(
+            &self.user, org, dashboard, member, start, end, options,
+        )?)
+    }
+
+    /// Fetches a single page of the members of an organization's dashboard.
+    ///
+    /// ## Documentation
+    /// * [Org Dashboard Members](https://wakatime.com/developers#org_dashboard_members)
+    pub fn org_dashboard_members(
+        &self,
+        org: &str,
+        dashboard: &str,
+        page: Option<u32>,
+    ) -> Result<model::Page<model::orgs::OrgDashboardMember>, ApiError> {
+        self.execute(endpoint::org_dashboard_members(
+            &self.user, org, dashboard, page,
+        )?)
+    }
+
+    /// Fetches the members of an organization's dashboard, following all pages.
+    ///
+    /// ## Documentation
+    /// * [Org Dashboard Members](https://wakatime.com/developers#org_dashboard_members)
+    pub fn org_dashboard_members_iter<'a>(
+        &'a self,
+        org: &'a str,
+        dashboard: &'a str,
+        pagination: PaginationOptions,
+    ) -> impl Iterator<Item = Result<model::orgs::OrgDashboardMember, ApiError>> + 'a {
+        self.paginate(pagination, move |client, page| {
+            client.org_dashboard_members(org, dashboard, Some(page))
+        })
+    }
+
+    /// Lists the private leaderboards the user belongs to.
+    ///
+    /// ## Documentation
+    /// * [Private Leaderboards](https://wakatime.com/developers#private_leaderboards)
+    pub fn private_leaderboards(&self) -> Result<model::leaders::PrivateLeaderboards, ApiError> {
+        self.execute(endpoint::private_leaderboards(&self.user))
+    }
+
+    /// Fetches a single page of the leaders of a private leaderboard.
+    ///
+    /// ## Documentation
+    /// * [Private Leaderboards Leaders](https://wakatime.com/developers#private_leaderboards_leaders)
+    pub fn private_leaderboard_leaders(
+        &self,
+        board: &str,
+        page: Option<u32>,
+    ) -> Result<model::leaders::Leaders, ApiError> {
+        self.execute(endpoint::private_leaderboard_leaders(
+            &self.user, board, page,
+        )?)
+    }
+
+    /// Fetches the leaders of a private leaderboard, following all pages.
+    ///
+    /// ## Documentation
+    /// * [Private Leaderboards Leaders](https://wakatime.com/developers#private_leaderboards_leaders)
+    pub fn private_leaderboard_leaders_iter<'a>(
+        &'a self,
+        board: &'a str,
+        pagination: PaginationOptions,
+    ) -> impl Iterator<Item = Result<model::leaders::Leader, ApiError>> + 'a {
+        self.paginate(pagination, move |client, page| {
+            client.private_leaderboard_leaders(board, Some(page))
+        })
+    }
+
+    /// ## Documentation
+    /// * [Projects](https://wakatime.com/developers#projects)
+    pub fn projects(
+        &self,
+        options: ProjectsOptions,
+    ) -> Result<Vec<model::projects::Project>, ApiError> {
+        self.execute(endpoint::projects(&self.user, options))
+    }
+
+    /// ## Documentation
+    /// * [Stats](https://wakatime.com/developers#stats)
+    pub fn stats(
+        &self,
+        range: StatsRange,
+        options: StatsOptions,
+    ) -> Result<model::Stats, ApiError> {
+        self.execute(endpoint::stats(&self.user, range, options))
+    }
+
+    /// Fetches today's coding activity, as shown in the editor status bars.
+    ///
+    /// ## Documentation
+    /// * [Status Bar](https://wakatime.com/developers#status_bar)
+    pub fn status_bar_today(&self) -> Result<model::StatusBar, ApiError> {
+        self.execute(endpoint::status_bar_today(&self.user))
+    }
+
+    /// ## Documentation
+    /// * [Summaries](https://wakatime.com/developers#summaries)
+    pub fn summaries<D: ApiDate>(
+        &self,
+        start: D,
+        end: D,
+        options: SummariesOptions,
+    ) -> Result<model::Summaries, ApiError> {
+        self.execute(endpoint::summaries(&self.user, start, end, options))
+    }
+
+    /// Fetches the summaries of every day in a preset range, such as the last 7 days.
+    ///
+    /// The `range` takes precedence over the range given in the `options`.
+    ///
+    /// ## Documentation
+    /// * [Summaries](https://wakatime.com/developers#summaries)
+    pub fn summaries_for_range(
+        &self,
+        range: SummariesRange,
+        options: SummariesOptions,
+    ) -> Result<model::Summaries, ApiError> {
+        self.execute(endpoint::summaries_for_range(&self.user, range, options))
+    }
+
+    /// Fetches the summaries of every day in the given range.
+    ///
+    /// ## Documentation
+    /// * [Summaries](https://wakatime.com/developers#summaries)
+    #[cfg(feature = "time")]
+    pub fn summaries_in(
+        &self,
+        range: DateRange,
+        options: SummariesOptions,
+    ) -> Result<model::Summaries, ApiError> {
+        self.summaries(range.start(), range.end(), options)
+    }
+
+    /// Fetches the profile of the user, including the timezone and keystroke timeout
+    /// used for calculating their coding activity.
+    ///
+    /// ## Documentation
+    /// * [Users](https://wakatime.com/developers#users)
+    pub fn user(&self) -> Result<model::user::User, ApiError> {
+        self.execute(endpoint::user(&self.user))
+    }
+
+    /// Lists the plugins the user sent heartbeats with, including their editor and version.
+    ///
+    /// ## Documentation
+    /// * [User Agents](https://wakatime.com/developers#user_agents)
+    pub fn user_agents(&self) -> Result<Vec<model::user_agents::UserAgent>, ApiError> {
+        self.execute(endpoint::user_agents(&self.user))
+    }
+
+    /// Walks all pages of a paginated endpoint, starting at the first page.
+    ///
+    /// The `fetch` function is called with the client and the number of each page to fetch.
+    /// Pages are fetched lazily as the iterator is consumed; after the first error, the iterator ends.
+    pub fn paginate<'a, F, P>(
+        &'a self,
+        options: PaginationOptions,
+        fetch: F,
+    ) -> impl Iterator<Item = Result<P::Item, ApiError>> + 'a
+    where
+        F: FnMut(&'a Self, u32) -> Result<P, ApiError> + 'a,
+        P: Paginated + 'a,
+    {
+        let mut state = PaginationState::new(fetch, options);
+        std::iter::from_fn(move || loop {
+            if let Some(item) = state.items.pop_front() {
+                return Some(Ok(item));
+            }
+
+            let page = state.page_to_fetch()?;
+            match (state.fetch)(self, page) {
+                Ok(page) => state.push_page(page),
+                Err(e) => {
+                    state.stop();
+                    return Some(Err(e));
+                }
+            }
+        })
+    }
+
+    /// Executes the request of an endpoint, polling it while its data is not up to date
+    /// if enabled via [`WakaTimeClientBuilder::with_polling`](crate::WakaTimeClientBuilder::with_polling).
+    fn execute<T>(&self, endpoint: Endpoint<T>) -> Result<T, ApiError> {
+        let mut poll = self
+            .polling
+            .as_ref()
+            .filter(|_| endpoint.may_be_calculating)
+            .map(Poll::new);
+
+        loop {
+            let result = self.send(&endpoint);
+            if let Err(ApiError::NotUpToDate { .. }) = result {
+                if let Some(interval) = poll.as_mut().and_then(Poll::next_interval) {
+                    self.transport.sleep(interval);
+                    continue;
+                }
+            }
+            return result;
+        }
+    }
+
+    /// Sends the request of an endpoint, retrying it according to the [`RetryPolicy`].
+    fn send<T>(&self, endpoint: &Endpoint<T>) -> Result<T, ApiError> {
+        endpoint.check_scope(self.scopes.as_deref())?;
+        let request = endpoint.to_request(&self.base_url, &self.headers);
+        let mut attempts = 1;
+        loop {
+            let result = self.transport.send(request.clone());
+            match endpoint.outcome(&request, result, &self.retry_policy, attempts) {
+                Outcome::Done(result) => return result,
+                Outcome::Retry(backoff) => {
+                    self.transport.sleep(backoff);
+                    attempts += 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::FixtureTransport;
+    use crate::transport::Method;
+    use crate::WakaTimeClientBuilder;
+
+    #[test]
+    fn client_sends_requests_and_paginates() {
+        let transport = FixtureTransport::new();
+        let client = WakaTimeClientBuilder::new_with_api_key("waka_test")
+            .with_blocking_transport(transport.clone())
+            .build_blocking()
+            .unwrap();
+
+        let members = client
+            .org_dashboard_members_iter("org", "dashboard", PaginationOptions::default())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(members.len(), 2);
+        assert_eq!(transport.requests().len(), 1);
+
+        transport.respond_with_status(Method::Get, "goals", 404);
+        assert!(matches!(client.goals(), Err(ApiError::NotFound(_))));
+    }
+}
@@ -1,17 +1,22 @@
-use reqwest::header::InvalidHeaderValue;
+use crate::transport::TransportError;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug)]
 pub enum BuilderError {
-    InvalidHeader(InvalidHeaderValue),
-    ClientError(reqwest::Error),
+    /// A header value, such as the API key, contains characters not allowed in HTTP headers.
+    InvalidHeader(String),
+    /// The user is `.` or `..`, which cannot be used in the path.
+    InvalidUser(String),
+    /// The HTTP transport could not be constructed.
+    ClientError(TransportError),
 }
 
 impl Display for BuilderError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::InvalidHeader(e) => write!(f, "An invalid header was provided: {e}"),
+            Self::InvalidHeader(name) => write!(f, "An invalid header was provided: {name}"),
+            Self::InvalidUser(user) => write!(f, "An invalid user was provided: {user}"),
             Self::ClientError(e) => write!(f, "Failed to construct the HTTP client: {e}"),
         }
     }
@@ -19,14 +24,8 @@ impl Display for BuilderError {
 
 impl Error for BuilderError {}
 
-impl From<InvalidHeaderValue> for BuilderError {
-    fn from(value: InvalidHeaderValue) -> Self {
-        Self::InvalidHeader(value)
-    }
-}
-
-impl From<reqwest::Error> for BuilderError {
-    fn from(value: reqwest::Error) -> Self {
+impl From<TransportError> for BuilderError {
+    fn from(value: TransportError) -> Self {
         Self::ClientError(value)
     }
 }
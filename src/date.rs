@@ -0,0 +1,158 @@
+//! Calendar dates as accepted by the API.
+
+#[cfg(feature = "time")]
+use std::error::Error;
+#[cfg(feature = "time")]
+use std::fmt::{Display, Formatter};
+#[cfg(feature = "time")]
+use time::format_description::well_known::Rfc3339;
+#[cfg(feature = "time")]
+use time::{Date, OffsetDateTime};
+
+/// The format of dates used by the API, `YEAR-MONTH-DAY`.
+#[cfg(feature = "time")]
+const DATE_FORMAT: &[time::format_description::FormatItem<'static>] =
+    time::macros::format_description!("[year]-[month]-[day]");
+
+/// A calendar date that can be passed to the API, e.g. `"2023-01-01"`.
+///
+/// When the `time` feature is enabled, this is also implemented for `time::Date`.
+pub trait ApiDate {
+    /// Formats the date as `YEAR-MONTH-DAY`.
+    fn to_api_date(&self) -> String;
+}
+
+impl ApiDate for &str {
+    fn to_api_date(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ApiDate for String {
+    fn to_api_date(&self) -> String {
+        self.clone()
+    }
+}
+
+#[cfg(feature = "time")]
+impl ApiDate for Date {
+    fn to_api_date(&self) -> String {
+        self.format(DATE_FORMAT)
+            .expect("formatting a date as YEAR-MONTH-DAY cannot fail")
+    }
+}
+
+/// An inclusive range of calendar dates where the start is never after the end.
+#[cfg(feature = "time")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DateRange {
+    start: Date,
+    end: Date,
+}
+
+#[cfg(feature = "time")]
+impl DateRange {
+    /// Creates a new range from `start` to `end`, both inclusive.
+    pub fn new(start: Date, end: Date) -> Result<Self, DateRangeError> {
+        if start > end {
+            return Err(DateRangeError { start, end });
+        }
+        Ok(Self { start, end })
+    }
+
+    /// Creates a range spanning a single day.
+    pub fn single_day(date: Date) -> Self {
+        Self {
+            start: date,
+            end: date,
+        }
+    }
+
+    /// Gets the first day of the range.
+    pub fn start(&self) -> Date {
+        self.start
+    }
+
+    /// Gets the last day of the range.
+    pub fn end(&self) -> Date {
+        self.end
+    }
+}
+
+/// The start of a [`DateRange`] was after its end.
+#[cfg(feature = "time")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DateRangeError {
+    pub start: Date,
+    pub end: Date,
+}
+
+#[cfg(feature = "time")]
+impl Display for DateRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The start date {start} is after the end date {end}",
+            start = self.start.to_api_date(),
+            end = self.end.to_api_date()
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl Error for DateRangeError {}
+
+/// Parses a `YEAR-MONTH-DAY` date string as returned by the API.
+#[cfg(feature = "time")]
+pub(crate) fn parse_date(value: &str) -> Result<Date, time::error::Parse> {
+    Date::parse(value, DATE_FORMAT)
+}
+
+/// Parses an ISO 8601 datetime string as returned by the API.
+#[cfg(feature = "time")]
+pub(crate) fn parse_datetime(value: &str) -> Result<OffsetDateTime, time::error::Parse> {
+    OffsetDateTime::parse(value, &Rfc3339)
+}
+
+#[cfg(all(test, feature = "time"))]
+mod tests {
+    use super::*;
+    use time::macros::{date, datetime};
+
+    #[test]
+    fn range_rejects_start_after_end() {
+        let range = DateRange::new(date!(2023 - 01 - 08), date!(2023 - 01 - 01));
+        assert_eq!(
+            range,
+            Err(DateRangeError {
+                start: date!(2023 - 01 - 08),
+                end: date!(2023 - 01 - 01),
+            })
+        );
+
+        let range = DateRange::new(date!(2023 - 01 - 01), date!(2023 - 01 - 01)).unwrap();
+        assert_eq!(range, DateRange::single_day(date!(2023 - 01 - 01)));
+    }
+
+    #[test]
+    fn dates_are_zero_padded() {
+        assert_eq!(date!(2023 - 01 - 02).to_api_date(), "2023-01-02");
+        assert_eq!(date!(0999 - 12 - 31).to_api_date(), "0999-12-31");
+    }
+
+    #[test]
+    fn fixture_formats_are_parsed() {
+        assert_eq!(parse_date("2023-07-09").unwrap(), date!(2023 - 07 - 09));
+        assert!(parse_date("2023-7-9").is_err());
+
+        assert_eq!(
+            parse_datetime("2023-07-08T14:10:00Z").unwrap(),
+            datetime!(2023-07-08 14:10:00 UTC)
+        );
+        assert_eq!(
+            parse_datetime("2023-07-08T16:10:00.5+02:00").unwrap(),
+            datetime!(2023-07-08 14:10:00.5 UTC)
+        );
+        assert!(parse_datetime("2023-07-08").is_err());
+    }
+}
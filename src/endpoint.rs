@@ -0,0 +1,697 @@
+//! Describes the requests of all endpoints, shared by the async and blocking clients.
+
+use crate::model::heartbeats::{
+    BulkHeartbeatResult, BulkHeartbeatsResponse, CreatedHeartbeat, NewHeartbeat,
+};
+use crate::model::Page;
+use crate::oauth::Scope;
+use crate::retry::{self, RetryPolicy};
+use crate::transport::{Method, Request, Response, TransportError};
+use crate::{
+    model, AllTimesSinceTodayOptions, ApiDate, ApiError, CommitOptions, CommitsOptions,
+    DataWrapper, DurationsOptions, ErrorContext, ErrorsResponse, IntoQueryString, LeadersOptions,
+    ProjectsOptions, StatsOptions, StatsRange, SummariesOptions, SummariesRange, CURRENT_USER,
+};
+use percent_encoding::{utf8_percent_encode, AsciiSet, PercentEncode, CONTROLS};
+use query_string_builder::QueryString;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// The characters to encode in a path segment, see
+/// <https://url.spec.whatwg.org/#path-percent-encode-set>, plus `/`, `\` and `%`.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}')
+    .add(b'/')
+    .add(b'\\')
+    .add(b'%');
+
+/// A request to a single endpoint and the means to interpret its response.
+pub(crate) struct Endpoint<T> {
+    /// The name of the endpoint, as reported in errors.
+    pub name: &'static str,
+    /// The OAuth scope required to call the endpoint, if any.
+    pub scope: Option<Scope>,
+    pub method: Method,
+    /// The path relative to the base URL, including the query string.
+    pub path: String,
+    /// The JSON encoded body, if any.
+    pub body: Option<Vec<u8>>,
+    /// The status codes indicating success.
+    pub expected: &'static [u16],
+    /// Whether the endpoint responds with `202 Accepted` while its data is not up to date.
+    pub may_be_calculating: bool,
+    /// Decodes the body of a successful response.
+    pub decode: fn(&[u8]) -> Result<T, DecodeError>,
+}
+
+impl<T> Endpoint<T> {
+    fn get(
+        name: &'static str,
+        scope: Option<Scope>,
+        path: String,
+        decode: fn(&[u8]) -> Result<T, DecodeError>,
+    ) -> Self {
+        Self {
+            name,
+            scope,
+            method: Method::Get,
+            path,
+            body: None,
+            expected: &[200],
+            may_be_calculating: false,
+            decode,
+        }
+    }
+
+    fn post<B: Serialize + ?Sized>(
+        name: &'static str,
+        scope: Option<Scope>,
+        path: String,
+        body: &B,
+        decode: fn(&[u8]) -> Result<T, DecodeError>,
+    ) -> Self {
+        Self {
+            name,
+            scope,
+            method: Method::Post,
+            path,
+            body: Some(serde_json::to_vec(body).expect("request bodies serialize to JSON")),
+            expected: &[201, 202],
+            may_be_calculating: false,
+            decode,
+        }
+    }
+
+    fn calculated_in_background(self) -> Self {
+        Self {
+            may_be_calculating: true,
+            ..self
+        }
+    }
+
+    /// Ensures the scope required by this endpoint was granted, if the granted scopes are known.
+    pub fn check_scope(&self, granted: Option<&[Scope]>) -> Result<(), ApiError> {
+        match (self.scope, granted) {
+            (Some(scope), Some(granted)) if !granted.contains(&scope) => {
+                Err(ApiError::MissingScope(scope))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Builds the request to this endpoint.
+    pub fn to_request(&self, base_url: &str, headers: &[(String, String)]) -> Request {
+        let mut headers = headers.to_vec();
+        if self.body.is_some() {
+            headers.push(("content-type".to_string(), "application/json".to_string()));
+        }
+
+        Request {
+            method: self.method,
+            url: format!("{base_url}{path}", path = self.path),
+            headers,
+            body: self.body.clone(),
+        }
+    }
+
+    /// Interprets the result of sending the request for the given number of attempts,
+    /// deciding whether to retry it according to the [`RetryPolicy`].
+    pub fn outcome(
+        &self,
+        request: &Request,
+        result: Result<Response, TransportError>,
+        policy: &RetryPolicy,
+        attempts: u32,
+    ) -> Outcome<T> {
+        let can_retry = attempts < policy.max_attempts;
+        match result {
+            Err(e) if can_retry && e.is_retryable() => {
+                Outcome::Retry(policy.exponential_backoff(attempts))
+            }
+            Err(e) => Outcome::Done(Err(ApiError::RequestFailed(e))),
+            Ok(response) => {
+                let retry_after = retry::retry_after(&response);
+                let backoff = Some(response.status)
+                    .filter(|&status| can_retry && retry::is_retryable(status))
+                    .and_then(|_| policy.backoff(attempts, retry_after));
+                match backoff {
+                    Some(backoff) => Outcome::Retry(backoff),
+                    None => {
+                        let url = request.url.clone();
+                        Outcome::Done(self.parse_response(&response, url, retry_after))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Interprets the response to this request.
+    fn parse_response(
+        &self,
+        response: &Response,
+        url: String,
+        retry_after: Option<Duration>,
+    ) -> Result<T, ApiError> {
+        let body = response.body.as_slice();
+        match response.status {
+            code if self.expected.contains(&code) => (self.decode)(body).map_err(|e| match e {
+                DecodeError::InvalidFormat(source) => ApiError::InvalidFormat {
+                    context: ErrorContext {
+                        endpoint: self.name,
+                        url,
+                        errors: None,
+                    },
+                    source,
+                },
+                DecodeError::PartialFailure(results) => ApiError::PartialFailure(results),
+            }),
+            202 => {
+                let progress = serde_json::from_slice::<DataWrapper<CalculationProgress>>(body);
+                Err(ApiError::NotUpToDate {
+                    percent_calculated: progress.ok().and_then(|p| p.data.percent_calculated),
+                })
+            }
+            other => {
+                let context = ErrorContext {
+                    endpoint: self.name,
+                    url,
+                    errors: serde_json::from_slice::<ErrorsResponse>(body).ok(),
+                };
+                match other {
+                    400 => Err(ApiError::BadRequest {
+                        context,
+                        body: String::from_utf8_lossy(body).into_owned(),
+                    }),
+                    401 => Err(ApiError::Unauthorized(context)),
+                    403 => Err(ApiError::Forbidden(context)),
+                    404 => Err(ApiError::NotFound(context)),
+                    429 => Err(ApiError::RateLimited {
+                        context,
+                        retry_after,
+                    }),
+                    500..=599 => Err(ApiError::ServerError(other, context)),
+                    other => Err(ApiError::Unspecified(other, context)),
+                }
+            }
+        }
+    }
+}
+
+/// The outcome of a single attempt of sending a request.
+pub(crate) enum Outcome<T> {
+    /// The request completed, successfully or not.
+    Done(Result<T, ApiError>),
+    /// The request should be retried after the given time.
+    Retry(Duration),
+}
+
+/// The reasons the body of a successful response may be rejected.
+pub(crate) enum DecodeError {
+    /// The body could not be deserialized.
+    InvalidFormat(serde_json::Error),
+    /// Some heartbeats of a bulk request were rejected.
+    PartialFailure(Vec<BulkHeartbeatResult>),
+}
+
+/// The calculation progress reported by endpoints that respond with `202 Accepted`
+/// while their data is not up to date.
+#[derive(Debug, Clone, Deserialize)]
+struct CalculationProgress {
+    percent_calculated: Option<u8>,
+}
+
+/// Percent-encodes a value for use as a single segment of the path.
+///
+/// `.` and `..` are rejected, since the URL parser resolves them even when encoded,
+/// which would send the request to another endpoint.
+pub(crate) fn segment(value: &str) -> Result<PercentEncode<'_>, ApiError> {
+    match value {
+        "." | ".." => Err(ApiError::InvalidPathSegment(value.to_string())),
+        _ => Ok(utf8_percent_encode(value, PATH_SEGMENT)),
+    }
+}
+
+/// Decodes a JSON body.
+fn json<T: DeserializeOwned>(body: &[u8]) -> Result<T, DecodeError> {
+    serde_json::from_slice(body).map_err(DecodeError::InvalidFormat)
+}
+
+/// Decodes a JSON body, unwrapping its `data` field.
+fn json_data<T: DeserializeOwned>(body: &[u8]) -> Result<T, DecodeError> {
+    json::<DataWrapper<T>>(body).map(|r| r.data)
+}
+
+pub(crate) fn all_time_since_today(
+    user: &str,
+    options: AllTimesSinceTodayOptions,
+) -> Endpoint<model::AllTimeSinceToday> {
+    let qs = options.into_query_string();
+    let path = format!("users/{user}/all_time_since_today{qs}");
+    Endpoint::get(
+        "all_time_since_today",
+        Some(Scope::ReadStats),
+        path,
+        json_data,
+    )
+    .calculated_in_background()
+}
+
+pub(crate) fn commit(
+    user: &str,
+    project: &str,
+    hash: &str,
+    options: CommitOptions,
+) -> Result<Endpoint<model::Commits>, ApiError> {
+    let qs = options.into_query_string();
+    let path = format!(
+        "users/{user}/projects/{project}/commits/{hash}{qs}",
+        project = segment(project)?,
+        hash = segment(hash)?
+    );
+    Ok(Endpoint::get(
+        "commit",
+        Some(Scope::ReadLoggedTime),
+        path,
+        json,
+    ))
+}
+
+pub(crate) fn commits(
+    user: &str,
+    project: &str,
+    options: CommitsOptions,
+) -> Result<Endpoint<model::commit::CommitsPage>, ApiError> {
+    let qs = options.into_query_string();
+    let path = format!(
+        "users/{user}/projects/{project}/commits{qs}",
+        project = segment(project)?
+    );
+    Ok(Endpoint::get(
+        "commits",
+        Some(Scope::ReadLoggedTime),
+        path,
+        json,
+    ))
+}
+
+pub(crate) fn durations<D: ApiDate>(
+    user: &str,
+    date: D,
+    options: DurationsOptions,
+) -> Endpoint<model::Durations> {
+    let qs = options
+        .into_query_string()
+        .with_value("date", date.to_api_date());
+    let path = format!("users/{user}/durations{qs}");
+    Endpoint::get("durations", Some(Scope::ReadLoggedTime), path, json)
+}
+
+pub(crate) fn goals(user: &str) -> Endpoint<model::Goals> {
+    Endpoint::get(
+        "goals",
+        Some(Scope::ReadGoals),
+        format!("users/{user}/goals"),
+        json,
+    )
+}
+
+pub(crate) fn goal(user: &str, id: &str) -> Result<Endpoint<model::goals::Goal>, ApiError> {
+    Ok(Endpoint::get(
+        "goal",
+        Some(Scope::ReadGoals),
+        format!("users/{user}/goals/{id}", id = segment(id)?),
+        json_data,
+    ))
+}
+
+pub(crate) fn heartbeats<D: ApiDate>(user: &str, date: D) -> Endpoint<model::Heartbeats> {
+    let qs = QueryString::new().with_value("date", date.to_api_date());
+    Endpoint::get(
+        "heartbeats",
+        Some(Scope::ReadLoggedTime),
+        format!("users/{user}/heartbeats{qs}"),
+        json,
+    )
+}
+
+pub(crate) fn send_heartbeat(heartbeat: &NewHeartbeat) -> Endpoint<CreatedHeartbeat> {
+    let path = format!("users/{CURRENT_USER}/heartbeats");
+    Endpoint::post(
+        "send_heartbeat",
+        Some(Scope::WriteLoggedTime),
+        path,
+        heartbeat,
+        json_data,
+    )
+}
+
+pub(crate) fn send_heartbeats_bulk(
+    heartbeats: &[NewHeartbeat],
+) -> Endpoint<Vec<BulkHeartbeatResult>> {
+    let path = format!("users/{CURRENT_USER}/heartbeats.bulk");
+    Endpoint::post(
+        "send_heartbeats_bulk",
+        Some(Scope::WriteLoggedTime),
+        path,
+        heartbeats,
+        |body| {
+            let results = Vec::from(json::<BulkHeartbeatsResponse>(body)?);
+            if results.iter().all(|r| r.is_success()) {
+                Ok(results)
+            } else {
+                Err(DecodeError::PartialFailure(results))
+            }
+        },
+    )
+}
+
+pub(crate) fn leaders(options: LeadersOptions) -> Endpoint<model::leaders::Leaders> {
+    let qs = options.into_query_string();
+    Endpoint::get("leaders", None, format!("leaders{qs}"), json)
+}
+
+pub(crate) fn machine_names(user: &str) -> Endpoint<Vec<model::machine_names::MachineName>> {
+    let path = format!("users/{user}/machine_names");
+    Endpoint::get(
+        "machine_names",
+        Some(Scope::ReadLoggedTime),
+        path,
+        json_data,
+    )
+}
+
+pub(crate) fn orgs(user: &str) -> Endpoint<model::orgs::Orgs> {
+    Endpoint::get(
+        "orgs",
+        Some(Scope::ReadOrgs),
+        format!("users/{user}/orgs"),
+        json,
+    )
+}
+
+pub(crate) fn org_dashboards(
+    user: &str,
+    org: &str,
+) -> Result<Endpoint<model::orgs::OrgDashboards>, ApiError> {
+    let path = format!("users/{user}/orgs/{org}/dashboards", org = segment(org)?);
+    Ok(Endpoint::get(
+        "org_dashboards",
+        Some(Scope::ReadOrgs),
+        path,
+        json,
+    ))
+}
+
+pub(crate) fn org_dashboard_members(
+    user: &str,
+    org: &str,
+    dashboard: &str,
+    page: Option<u32>,
+) -> Result<Endpoint<Page<model::orgs::OrgDashboardMember>>, ApiError> {
+    let qs = QueryString::new().with_opt_value("page", page.map(|v| v.to_string()));
+    let path = format!(
+        "users/{user}/orgs/{org}/dashboards/{dashboard}/members{qs}",
+        org = segment(org)?,
+        dashboard = segment(dashboard)?
+    );
+    Ok(Endpoint::get(
+        "org_dashboard_members",
+        Some(Scope::ReadOrgs),
+        path,
+        json,
+    ))
+}
+
+pub(crate) fn org_dashboard_member_durations<D: ApiDate>(
+    user: &str,
+    org: &str,
+    dashboard: &str,
+    member: &str,
+    date: D,
+    options: DurationsOptions,
+) -> Result<Endpoint<model::Durations>, ApiError> {
+    let qs = options
+        .into_query_string()
+        .with_value("date", date.to_api_date());
+    let path = format!(
+        "users/{user}/orgs/{org}/dashboards/{dashboard}/members/{member}/durations{qs}",
+        org = segment(org)?,
+        dashboard = segment(dashboard)?,
+        member = segment(member)?
+    );
+    Ok(Endpoint::get(
+        "org_dashboard_member_durations",
+        Some(Scope::ReadOrgs),
+        path,
+        json,
+    ))
+}
+
+pub(crate) fn org_dashboard_member_summaries<D: ApiDate>(
+    user: &str,
+    org: &str,
+    dashboard: &str,
+    member: &str,
+    start: D,
+    end: D,
+    options: SummariesOptions,
+) -> Result<Endpoint<model::Summaries>, ApiError> {
+    let qs = options
+        .into_query_string()
+        .with_value("start", start.to_api_date())
+        .with_value("end", end.to_api_date());
+    let path = format!(
+        "users/{user}/orgs/{org}/dashboards/{dashboard}/members/{member}/summaries{qs}",
+        org = segment(org)?,
+        dashboard = segment(dashboard)?,
+        member = segment(member)?
+    );
+    Ok(Endpoint::get(
+        "org_dashboard_member_summaries",
+        Some(Scope::ReadOrgs),
+        path,
+        json,
+    ))
+}
+
+pub(crate) fn private_leaderboards(user: &str) -> Endpoint<model::leaders::PrivateLeaderboards> {
+    let path = format!("users/{user}/leaderboards");
+    Endpoint::get(
+        "private_leaderboards",
+        Some(Scope::ReadPrivateLeaderboards),
+        path,
+        json,
+    )
+}
+
+pub(crate) fn private_leaderboard_leaders(
+    user: &str,
+    board: &str,
+    page: Option<u32>,
+) -> Result<Endpoint<model::leaders::Leaders>, ApiError> {
+    let qs = QueryString::new().with_opt_value("page", page.map(|v| v.to_string()));
+    let path = format!(
+        "users/{user}/leaderboards/{board}{qs}",
+        board = segment(board)?
+    );
+    Ok(Endpoint::get(
+        "private_leaderboard_leaders",
+        Some(Scope::ReadPrivateLeaderboards),
+        path,
+        json,
+    ))
+}
+
+pub(crate) fn projects(
+    user: &str,
+    options: ProjectsOptions,
+) -> Endpoint<Vec<model::projects::Project>> {
+    let qs = options.into_query_string();
+    Endpoint::get(
+        "projects",
+        Some(Scope::ReadLoggedTime),
+        format!("users/{user}/projects{qs}"),
+        json_data,
+    )
+}
+
+pub(crate) fn stats(
+    user: &str,
+    range: StatsRange,
+    options: StatsOptions,
+) -> Endpoint<model::Stats> {
+    let qs = options.into_query_string();
+    let path = format!("users/{user}/stats/{range}{qs}");
+    Endpoint::get("stats", Some(Scope::ReadStats), path, json_data).calculated_in_background()
+}
+
+pub(crate) fn status_bar_today(user: &str) -> Endpoint<model::StatusBar> {
+    let path = format!("users/{user}/status_bar/today");
+    Endpoint::get("status_bar_today", Some(Scope::ReadLoggedTime), path, json)
+}
+
+pub(crate) fn summaries<D: ApiDate>(
+    user: &str,
+    start: D,
+    end: D,
+    options: SummariesOptions,
+) -> Endpoint<model::Summaries> {
+    let qs = options
+        .into_query_string()
+        .with_value("start", start.to_api_date())
+        .with_value("end", end.to_api_date());
+    Endpoint::get(
+        "summaries",
+        Some(Scope::ReadLoggedTime),
+        format!("users/{user}/summaries{qs}"),
+        json,
+    )
+}
+
+pub(crate) fn summaries_for_range(
+    user: &str,
+    range: SummariesRange,
+    options: SummariesOptions,
+) -> Endpoint<model::Summaries> {
+    let options = SummariesOptions {
+        range: Some(range),
+        ..options
+    };
+    let qs = options.into_query_string();
+    let path = format!("users/{user}/summaries{qs}");
+    Endpoint::get(
+        "summaries_for_range",
+        Some(Scope::ReadLoggedTime),
+        path,
+        json,
+    )
+}
+
+pub(crate) fn user(user: &str) -> Endpoint<model::user::User> {
+    Endpoint::get("user", None, format!("users/{user}"), json_data)
+}
+
+pub(crate) fn user_agents(user: &str) -> Endpoint<Vec<model::user_agents::UserAgent>> {
+    let path = format!("users/{user}/user_agents");
+    Endpoint::get("user_agents", Some(Scope::ReadLoggedTime), path, json_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_segments_are_encoded() {
+        let endpoint = commits("current", "my project#1/x", CommitsOptions::default()).unwrap();
+        assert_eq!(
+            endpoint.path,
+            "users/current/projects/my%20project%231%2Fx/commits"
+        );
+
+        let endpoint = commit("current", "waka-rs", "abc?d", CommitOptions::default()).unwrap();
+        assert_eq!(
+            endpoint.path,
+            "users/current/projects/waka-rs/commits/abc%3Fd"
+        );
+
+        let endpoint = org_dashboard_members("current", "a b", "c%d", None).unwrap();
+        assert_eq!(
+            endpoint.path,
+            "users/current/orgs/a%20b/dashboards/c%25d/members"
+        );
+
+        let endpoint = commits("current", "a\\b", CommitsOptions::default()).unwrap();
+        assert_eq!(endpoint.path, "users/current/projects/a%5Cb/commits");
+    }
+
+    #[test]
+    fn dot_segments_are_rejected() {
+        for project in [".", ".."] {
+            assert!(matches!(
+                commits("current", project, CommitsOptions::default()),
+                Err(ApiError::InvalidPathSegment(segment)) if segment == project
+            ));
+        }
+        assert!(goal("current", "..").is_err());
+        assert!(org_dashboard_member_summaries(
+            "current",
+            "org",
+            "dashboard",
+            ".",
+            "2023-01-01",
+            "2023-01-07",
+            SummariesOptions::default(),
+        )
+        .is_err());
+        assert!(private_leaderboard_leaders("current", "..", None).is_err());
+        assert!(commits("current", "...", CommitsOptions::default()).is_ok());
+    }
+
+    #[test]
+    fn long_retry_after_is_reported_instead_of_waited_for() {
+        let endpoint = goals("current");
+        let request = endpoint.to_request("https://wakatime.com/api/v1/", &[]);
+        let response = |retry_after: &str| Response {
+            status: 429,
+            headers: vec![("Retry-After".to_string(), retry_after.to_string())],
+            body: Vec::new(),
+        };
+        let policy = RetryPolicy::default();
+
+        let outcome = endpoint.outcome(&request, Ok(response("86400")), &policy, 1);
+        assert!(matches!(
+            outcome,
+            Outcome::Done(Err(ApiError::RateLimited {
+                retry_after: Some(retry_after),
+                ..
+            })) if retry_after == Duration::from_secs(86_400)
+        ));
+
+        let outcome = endpoint.outcome(&request, Ok(response("5")), &policy, 1);
+        assert!(matches!(outcome, Outcome::Retry(backoff) if backoff == Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn undecodable_body_is_reported_with_its_context() {
+        let endpoint = goals("current");
+        let request = endpoint.to_request("https://wakatime.com/api/v1/", &[]);
+        let response = Response {
+            status: 200,
+            headers: Vec::new(),
+            body: b"{\"data\":".to_vec(),
+        };
+
+        let outcome = endpoint.outcome(&request, Ok(response), &RetryPolicy::default(), 1);
+        let Outcome::Done(Err(ApiError::InvalidFormat { context, .. })) = outcome else {
+            panic!("expected InvalidFormat");
+        };
+        assert_eq!(context.endpoint, "goals");
+        assert_eq!(
+            context.url,
+            "https://wakatime.com/api/v1/users/current/goals"
+        );
+    }
+
+    #[test]
+    fn scope_is_checked_if_granted_scopes_are_known() {
+        let endpoint = goals("current");
+        assert!(endpoint.check_scope(None).is_ok());
+        assert!(endpoint.check_scope(Some(&[Scope::ReadGoals])).is_ok());
+        assert!(matches!(
+            endpoint.check_scope(Some(&[Scope::ReadStats])),
+            Err(ApiError::MissingScope(Scope::ReadGoals))
+        ));
+
+        let endpoint = leaders(LeadersOptions::default());
+        assert!(endpoint.check_scope(Some(&[])).is_ok());
+    }
+}
@@ -19,66 +19,266 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! To act on behalf of other users, obtain an access token using the [`oauth`] flow and
+//! build the client using [`WakaTimeClientBuilder::new_with_oauth_token`].
+//!
+//! Requests are sent using [`reqwest`] by default, which requires a [Tokio](https://tokio.rs)
+//! runtime. A custom [`transport::Transport`] can be provided using
+//! [`WakaTimeClientBuilder::with_transport`], e.g. to run on another executor.
+//!
+//! ## Crate features
+//!
+//! * `blocking` - Provides a synchronous client in the `blocking` module.
+//! * `testing` - Provides a fixture-backed transport in the `testing` module for testing
+//!   code that uses the client without calling the WakaTime API.
+//! * `time` - Accept `time::Date` wherever the API expects a calendar date, provides the
+//!   validated `DateRange` and exposes the ISO 8601 strings of the models as typed values.
 
 mod api_error;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 mod builder_error;
+mod date;
+mod endpoint;
 pub mod model;
+pub mod oauth;
+mod polling;
+mod retry;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod transport;
 
-pub use crate::api_error::ApiError;
+pub use crate::api_error::{ApiError, ErrorContext};
 pub use crate::builder_error::BuilderError;
+pub use crate::date::ApiDate;
+#[cfg(feature = "time")]
+pub use crate::date::{DateRange, DateRangeError};
+use crate::endpoint::{Endpoint, Outcome};
+use crate::model::Paginated;
+use crate::oauth::{AccessToken, Scope};
+use crate::polling::Poll;
+pub use crate::polling::PollingPolicy;
+pub use crate::retry::RetryPolicy;
+use crate::transport::Transport;
 use base64::Engine;
+use futures_util::Stream;
 use query_string_builder::QueryString;
-use reqwest::header::HeaderValue;
-use reqwest::{header, Client, ClientBuilder, Response};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
 
-static BASE_URL: &'static str = "https://wakatime.com/api/v1/";
-const CURRENT_USER: &'static str = "current";
+const DEFAULT_BASE_URL: &str = "https://wakatime.com/api/v1/";
+const CURRENT_USER: &str = "current";
 
 /// A builder for [`WakaTimeClient`] instances.
 #[derive(Default)]
 pub struct WakaTimeClientBuilder {
-    /// The API key, base-64 encoded.
-    api_key_base64: String,
+    /// The value of the authorization header, e.g. `Basic <base-64 encoded API key>`.
+    authorization: String,
     /// The optional user to use.
     user: Option<String>,
+    /// The optional base URL of the API.
+    base_url: Option<String>,
+    /// The optional policy for polling data that is not up to date.
+    polling: Option<PollingPolicy>,
+    /// The optional policy for retrying failed requests.
+    retry_policy: Option<RetryPolicy>,
+    /// The scopes granted to the OAuth access token, if known.
+    scopes: Option<Vec<Scope>>,
+    /// The optional transport used by [`WakaTimeClient`].
+    transport: Option<Box<dyn Transport>>,
+    /// The optional transport used by [`blocking::WakaTimeClient`].
+    #[cfg(feature = "blocking")]
+    blocking_transport: Option<Box<dyn blocking::Transport>>,
 }
 
 impl WakaTimeClientBuilder {
     /// See [wakatime.com/api-key](https://wakatime.com/api-key).
     pub fn new_with_api_key<S: AsRef<str>>(api_key: S) -> Self {
         Self {
-            api_key_base64: base64::engine::general_purpose::STANDARD.encode(api_key.as_ref()),
+            authorization: format!(
+                "Basic {api_key}",
+                api_key = base64::engine::general_purpose::STANDARD.encode(api_key.as_ref())
+            ),
+            ..Default::default()
+        }
+    }
+
+    /// Authorizes requests with an access token obtained using the [`oauth`] flow,
+    /// allowing to act on behalf of other users.
+    pub fn new_with_oauth_token<S: AsRef<str>>(access_token: S) -> Self {
+        Self {
+            authorization: format!("Bearer {token}", token = access_token.as_ref()),
             ..Default::default()
         }
     }
 
+    /// Authorizes requests with an access token obtained using the [`oauth`] flow,
+    /// recording the scopes granted to it.
+    ///
+    /// If the token grants scopes not known to this crate, the scopes are not recorded
+    /// and all endpoints are called, as the unknown scopes may grant access to them.
+    pub fn new_with_access_token(token: &AccessToken) -> Self {
+        let builder = Self::new_with_oauth_token(&token.access_token);
+        if token.has_unknown_scopes() {
+            builder
+        } else {
+            builder.with_scopes(token.scopes())
+        }
+    }
+
+    /// Specifies the scopes granted to the OAuth access token. Calling an endpoint that
+    /// requires any other scope then fails with [`ApiError::MissingScope`] without sending
+    /// a request.
+    ///
+    /// If unspecified, all endpoints are called.
+    pub fn with_scopes<I: IntoIterator<Item = Scope>>(mut self, scopes: I) -> Self {
+        self.scopes = Some(scopes.into_iter().collect());
+        self
+    }
+
     /// Specifies a user to focus on. If unspecified, `current` is used.
     pub fn with_user<S: AsRef<str>>(mut self, user: S) -> Self {
         self.user = Some(user.as_ref().to_string());
         self
     }
 
-    pub fn build(self) -> Result<WakaTimeClient, BuilderError> {
-        let mut headers = header::HeaderMap::new();
-        let authorize = format!("Basic {api_key}", api_key = self.api_key_base64);
-        headers.insert("authorization", HeaderValue::from_str(&authorize)?);
+    /// Specifies the base URL of the API, e.g. `https://wakapi.dev/api/compat/wakatime/v1/`
+    /// for a [Wakapi](https://github.com/muety/wakapi) instance. If unspecified,
+    /// `https://wakatime.com/api/v1/` is used.
+    pub fn with_base_url<S: AsRef<str>>(mut self, base_url: S) -> Self {
+        self.base_url = Some(base_url.as_ref().to_string());
+        self
+    }
 
-        let client = ClientBuilder::new().default_headers(headers).build()?;
+    /// Enables polling of endpoints that respond with `202 Accepted` while the server is
+    /// still calculating their data, such as [`WakaTimeClient::stats`] and
+    /// [`WakaTimeClient::all_time_since_today`].
+    ///
+    /// If unspecified, [`ApiError::NotUpToDate`] is returned immediately.
+    pub fn with_polling(mut self, policy: PollingPolicy) -> Self {
+        self.polling = Some(policy);
+        self
+    }
+
+    /// Enables retrying of requests that were rate limited or failed with a server error.
+    ///
+    /// If unspecified, requests are not retried.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Specifies the transport sending the requests of the [`WakaTimeClient`].
+    /// If unspecified, [`ReqwestTransport`](transport::ReqwestTransport) is used.
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Some(Box::new(transport));
+        self
+    }
+
+    /// Specifies the transport sending the requests of the [`blocking::WakaTimeClient`].
+    /// If unspecified, [`blocking::ReqwestTransport`] is used.
+    #[cfg(feature = "blocking")]
+    pub fn with_blocking_transport<T: blocking::Transport + 'static>(
+        mut self,
+        transport: T,
+    ) -> Self {
+        self.blocking_transport = Some(Box::new(transport));
+        self
+    }
+
+    pub fn build(mut self) -> Result<WakaTimeClient, BuilderError> {
+        let transport = match self.transport.take() {
+            Some(transport) => transport,
+            None => Box::new(transport::ReqwestTransport::new()?),
+        };
 
         Ok(WakaTimeClient {
-            client,
-            user: self.user.unwrap_or(CURRENT_USER.to_string()),
+            transport,
+            headers: self.default_headers()?,
+            base_url: self.normalized_base_url(),
+            user: self.encoded_user()?,
+            polling: self.polling,
+            retry_policy: self.retry_policy.unwrap_or_else(RetryPolicy::none),
+            scopes: self.scopes,
         })
     }
+
+    /// Builds a [`blocking::WakaTimeClient`] for use outside of an async runtime.
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(mut self) -> Result<blocking::WakaTimeClient, BuilderError> {
+        let transport = match self.blocking_transport.take() {
+            Some(transport) => transport,
+            None => Box::new(blocking::ReqwestTransport::new()?),
+        };
+
+        Ok(blocking::WakaTimeClient {
+            transport,
+            headers: self.default_headers()?,
+            base_url: self.normalized_base_url(),
+            user: self.encoded_user()?,
+            polling: self.polling,
+            retry_policy: self.retry_policy.unwrap_or_else(RetryPolicy::none),
+            scopes: self.scopes,
+        })
+    }
+
+    /// Gets the headers sent with every request.
+    fn default_headers(&self) -> Result<Vec<(String, String)>, BuilderError> {
+        Ok(vec![header("authorization", self.authorization.clone())?])
+    }
+
+    /// Gets the user to use, percent-encoded for use in the path.
+    fn encoded_user(&self) -> Result<String, BuilderError> {
+        let user = self.user.as_deref().unwrap_or(CURRENT_USER);
+        endpoint::segment(user)
+            .map(|user| user.to_string())
+            .map_err(|_| BuilderError::InvalidUser(user.to_string()))
+    }
+
+    /// Gets the base URL of the API, ending in a slash.
+    fn normalized_base_url(&self) -> String {
+        let mut base_url = self
+            .base_url
+            .clone()
+            .unwrap_or(DEFAULT_BASE_URL.to_string());
+        if !base_url.ends_with('/') {
+            base_url.push('/');
+        }
+        base_url
+    }
+}
+
+/// Creates a header, ensuring its value only contains characters allowed in HTTP headers.
+fn header(name: &str, value: String) -> Result<(String, String), BuilderError> {
+    if value
+        .bytes()
+        .all(|b| b == b'\t' || (0x20..0x7f).contains(&b))
+    {
+        Ok((name.to_string(), value))
+    } else {
+        Err(BuilderError::InvalidHeader(name.to_string()))
+    }
 }
 
 /// A client for accessing the WakaTime API.
 pub struct WakaTimeClient {
-    /// The HTTP client to use.
-    client: Client,
-    /// The user to use.
+    /// The transport sending the requests.
+    transport: Box<dyn Transport>,
+    /// The headers sent with every request, such as the authorization.
+    headers: Vec<(String, String)>,
+    /// The user to use, percent-encoded for use in the path.
     user: String,
+    /// The base URL of the API, always ending in a slash.
+    base_url: String,
+    /// The policy for polling data that is not up to date, if enabled.
+    polling: Option<PollingPolicy>,
+    /// The policy for retrying failed requests.
+    retry_policy: RetryPolicy,
+    /// The scopes granted to the OAuth access token, if known.
+    scopes: Option<Vec<Scope>>,
 }
 
 impl WakaTimeClient {
@@ -88,13 +288,8 @@ impl WakaTimeClient {
         &self,
         options: AllTimesSinceTodayOptions<'a>,
     ) -> Result<model::AllTimeSinceToday, ApiError> {
-        let qs = options.into_query_string();
-        let url = format!(
-            "{BASE_URL}users/{user}/all_time_since_today{qs}",
-            user = self.user
-        );
-        let response = self.client.get(url).send().await?;
-        Self::deserialize_as(response, |r: DataWrapper<model::AllTimeSinceToday>| r.data).await
+        self.execute(endpoint::all_time_since_today(&self.user, options))
+            .await
     }
 
     /// ## Documentation
@@ -105,47 +300,436 @@ impl WakaTimeClient {
         hash: &str,
         options: CommitOptions<'a>,
     ) -> Result<model::Commits, ApiError> {
-        let qs = options.into_query_string();
-        let url = format!(
-            "{BASE_URL}users/{user}/projects/{project}/commits/{hash}{qs}",
-            user = self.user
-        );
-        let response = self.client.get(url).send().await?;
-        Self::deserialize_as(response, |r| r).await
+        self.execute(endpoint::commit(&self.user, project, hash, options)?)
+            .await
+    }
+
+    /// Fetches a single page of the commits of a project.
+    ///
+    /// ## Documentation
+    /// * [Commits](https://wakatime.com/developers#commits)
+    pub async fn commits<'a>(
+        &self,
+        project: &str,
+        options: CommitsOptions<'a>,
+    ) -> Result<model::commit::CommitsPage, ApiError> {
+        self.execute(endpoint::commits(&self.user, project, options)?)
+            .await
+    }
+
+    /// Fetches the commits of a project, following all pages.
+    ///
+    /// The `page` of the `options` is ignored; use [`PaginationOptions`] to limit the pages fetched.
+    ///
+    /// ## Documentation
+    /// * [Commits](https://wakatime.com/developers#commits)
+    pub fn commits_stream<'a>(
+        &'a self,
+        project: &'a str,
+        options: CommitsOptions<'a>,
+        pagination: PaginationOptions,
+    ) -> impl Stream<Item = Result<model::commit::Commit, ApiError>> + 'a {
+        self.paginate(pagination, move |client, page| {
+            let options = CommitsOptions {
+                page: Some(page),
+                ..options.clone()
+            };
+            client.commits(project, options)
+        })
+    }
+
+    /// ## Documentation
+    /// * [Durations](https://wakatime.com/developers#durations)
+    pub async fn durations<'a, D: ApiDate>(
+        &self,
+        date: D,
+        options: DurationsOptions<'a>,
+    ) -> Result<model::Durations, ApiError> {
+        self.execute(endpoint::durations(&self.user, date, options))
+            .await
     }
 
+    /// ## Documentation
+    /// * [Goals](https://wakatime.com/developers#goals)
+    pub async fn goals(&self) -> Result<model::Goals, ApiError> {
+        self.execute(endpoint::goals(&self.user)).await
+    }
+
+    /// ## Documentation
+    /// * [Goal](https://wakatime.com/developers#goal)
+    pub async fn goal(&self, id: &str) -> Result<model::goals::Goal, ApiError> {
+        self.execute(endpoint::goal(&self.user, id)?).await
+    }
+
+    /// ## Documentation
+    /// * [Heartbeats](https://wakatime.com/developers#heartbeats)
+    pub async fn heartbeats<D: ApiDate>(&self, date: D) -> Result<model::Heartbeats, ApiError> {
+        self.execute(endpoint::heartbeats(&self.user, date)).await
+    }
+
+    /// Sends a single heartbeat for the current user.
+    ///
+    /// ## Documentation
+    /// * [Heartbeats](https://wakatime.com/developers#heartbeats)
+    pub async fn send_heartbeat(
+        &self,
+        heartbeat: &model::heartbeats::NewHeartbeat,
+    ) -> Result<model::heartbeats::CreatedHeartbeat, ApiError> {
+        self.execute(endpoint::send_heartbeat(heartbeat)).await
+    }
+
+    /// Sends multiple heartbeats for the current user in a single request.
+    ///
+    /// Returns the result of every heartbeat in the order they were sent. If the server
+    /// rejected any of them, [`ApiError::PartialFailure`] is returned instead.
+    ///
+    /// ## Documentation
+    /// * [Heartbeats](https://wakatime.com/developers#heartbeats)
+    pub async fn send_heartbeats_bulk(
+        &self,
+        heartbeats: &[model::heartbeats::NewHeartbeat],
+    ) -> Result<Vec<model::heartbeats::BulkHeartbeatResult>, ApiError> {
+        self.execute(endpoint::send_heartbeats_bulk(heartbeats))
+            .await
+    }
+
+    /// Fetches a single page of the public leaderboard of the last 7 days.
+    ///
+    /// ## Documentation
+    /// * [Leaders](https://wakatime.com/developers#leaders)
+    pub async fn leaders<'a>(
+        &self,
+        options: LeadersOptions<'a>,
+    ) -> Result<model::leaders::Leaders, ApiError> {
+        self.execute(endpoint::leaders(options)).await
+    }
+
+    /// Fetches the public leaderboard of the last 7 days, following all pages.
+    ///
+    /// The `page` of the `options` is ignored; use [`PaginationOptions`] to limit the pages fetched.
+    ///
+    /// ## Documentation
+    /// * [Leaders](https://wakatime.com/developers#leaders)
+    pub fn leaders_stream<'a>(
+        &'a self,
+        options: LeadersOptions<'a>,
+        pagination: PaginationOptions,
+    ) -> impl Stream<Item = Result<model::leaders::Leader, ApiError>> + 'a {
+        self.paginate(pagination, move |client, page| {
+            let options = LeadersOptions {
+                page: Some(page),
+                ..options.clone()
+            };
+            client.leaders(options)
+        })
+    }
+
+    /// Lists the machines the user sent heartbeats from, e.g. to resolve the
+    /// `machine_name_id` of the summaries.
+    ///
+    /// ## Documentation
+    /// * [Machine Names](https://wakatime.com/developers#machine_names)
+    pub async fn machine_names(&self) -> Result<Vec<model::machine_names::MachineName>, ApiError> {
+        self.execute(endpoint::machine_names(&self.user)).await
+    }
+
+    /// Lists the organizations the user belongs to.
+    ///
+    /// ## Documentation
+    /// * [Orgs](https://wakatime.com/developers#orgs)
+    pub async fn orgs(&self) -> Result<model::orgs::Orgs, ApiError> {
+        self.execute(endpoint::orgs(&self.user)).await
+    }
+
+    /// Lists the dashboards of an organization visible to the user.
+    ///
+    /// ## Documentation
+    /// * [Org Dashboards](https://wakatime.com/developers#org_dashboards)
+    pub async fn org_dashboards(&self, org: &str) -> Result<model::orgs::OrgDashboards, ApiError> {
+        self.execute(endpoint::org_dashboards(&self.user, org)?)
+            .await
+    }
+
+    /// Fetches the durations of a member of an organization's dashboard for a single day.
+    ///
+    /// ## Documentation
+    /// * [Org Dashboard Member Durations](https://wakatime.com/developers#org_dashboard_member_durations)
+    pub async fn org_dashboard_member_durations<'a, D: ApiDate>(
+        &self,
+        org: &str,
+        dashboard: &str,
+        member: &str,
+        date: D,
+        options: DurationsOptions<'a>,
+    ) -> Result<model::Durations, ApiError> {
+        self.execute(endpoint::org_dashboard_member_durations(
+            &self.user, org, dashboard, member, date, options,
+        )?)
+        .await
+    }
+
+    /// Fetches the summaries of a member of an organization's dashboard for every day in the given range.
+    ///
+    /// ## Documentation
+    /// * [Org Dashboard Member Summaries](https://wakatime.com/developers#org_dashboard_member_summaries)
+    pub async fn org_dashboard_member_summaries<'a, D: ApiDate>(
+        &self,
+        org: &str,
+        dashboard: &str,
+        member: &str,
+        start: D,
+        end: D,
+        options: SummariesOptions<'a>,
+    ) -> Result<model::Summaries, ApiError> {
+        self.execute(endpoint::org_dashboard_member_summaries(
+            &self.user, org, dashboard, member, start, end, options,
+        )?)
+        .await
+    }
+
+    /// Fetches a single page of the members of an organization's dashboard.
+    ///
+    /// ## Documentation
+    /// * [Org Dashboard Members](https://wakatime.com/developers#org_dashboard_members)
+    pub async fn org_dashboard_members(
+        &self,
+        org: &str,
+        dashboard: &str,
+        page: Option<u32>,
+    ) -> Result<model::Page<model::orgs::OrgDashboardMember>, ApiError> {
+        self.execute(endpoint::org_dashboard_members(
+            &self.user, org, dashboard, page,
+        )?)
+        .await
+    }
+
+    /// Fetches the members of an organization's dashboard, following all pages.
+    ///
+    /// ## Documentation
+    /// * [Org Dashboard Members](https://wakatime.com/developers#org_dashboard_members)
+    pub fn org_dashboard_members_stream<'a>(
+        &'a self,
+        org: &'a str,
+        dashboard: &'a str,
+        pagination: PaginationOptions,
+    ) -> impl Stream<Item = Result<model::orgs::OrgDashboardMember, ApiError>> + 'a {
+        self.paginate(pagination, move |client, page| {
+            client.org_dashboard_members(org, dashboard, Some(page))
+        })
+    }
+
+    /// Lists the private leaderboards the user belongs to.
+    ///
+    /// ## Documentation
+    /// * [Private Leaderboards](https://wakatime.com/developers#private_leaderboards)
+    pub async fn private_leaderboards(
+        &self,
+    ) -> Result<model::leaders::PrivateLeaderboards, ApiError> {
+        self.execute(endpoint::private_leaderboards(&self.user))
+            .await
+    }
+
+    /// Fetches a single page of the leaders of a private leaderboard.
+    ///
+    /// ## Documentation
+    /// * [Private Leaderboards Leaders](https://wakatime.com/developers#private_leaderboards_leaders)
+    pub async fn private_leaderboard_leaders(
+        &self,
+        board: &str,
+        page: Option<u32>,
+    ) -> Result<model::leaders::Leaders, ApiError> {
+        self.execute(endpoint::private_leaderboard_leaders(
+            &self.user, board, page,
+        )?)
+        .await
+    }
+
+    /// Fetches the leaders of a private leaderboard, following all pages.
+    ///
+    /// ## Documentation
+    /// * [Private Leaderboards Leaders](https://wakatime.com/developers#private_leaderboards_leaders)
+    pub fn private_leaderboard_leaders_stream<'a>(
+        &'a self,
+        board: &'a str,
+        pagination: PaginationOptions,
+    ) -> impl Stream<Item = Result<model::leaders::Leader, ApiError>> + 'a {
+        self.paginate(pagination, move |client, page| {
+            client.private_leaderboard_leaders(board, Some(page))
+        })
+    }
+
+    /// ## Documentation
+    /// * [Projects](https://wakatime.com/developers#projects)
+    pub async fn projects<'a>(
+        &self,
+        options: ProjectsOptions<'a>,
+    ) -> Result<Vec<model::projects::Project>, ApiError> {
+        self.execute(endpoint::projects(&self.user, options)).await
+    }
+
+    /// ## Documentation
+    /// * [Stats](https://wakatime.com/developers#stats)
+    pub async fn stats(
+        &self,
+        range: StatsRange,
+        options: StatsOptions,
+    ) -> Result<model::Stats, ApiError> {
+        self.execute(endpoint::stats(&self.user, range, options))
+            .await
+    }
+
+    /// Fetches today's coding activity, as shown in the editor status bars.
+    ///
+    /// ## Documentation
+    /// * [Status Bar](https://wakatime.com/developers#status_bar)
+    pub async fn status_bar_today(&self) -> Result<model::StatusBar, ApiError> {
+        self.execute(endpoint::status_bar_today(&self.user)).await
+    }
+
+    /// ## Documentation
+    /// * [Summaries](https://wakatime.com/developers#summaries)
+    pub async fn summaries<'a, D: ApiDate>(
+        &self,
+        start: D,
+        end: D,
+        options: SummariesOptions<'a>,
+    ) -> Result<model::Summaries, ApiError> {
+        self.execute(endpoint::summaries(&self.user, start, end, options))
+            .await
+    }
+
+    /// Fetches the summaries of every day in a preset range, such as the last 7 days.
+    ///
+    /// The `range` takes precedence over the range given in the `options`.
+    ///
     /// ## Documentation
     /// * [Summaries](https://wakatime.com/developers#summaries)
-    pub async fn summaries<'a>(
+    pub async fn summaries_for_range<'a>(
         &self,
-        start: &str,
-        end: &str,
+        range: SummariesRange,
         options: SummariesOptions<'a>,
     ) -> Result<model::Summaries, ApiError> {
-        let qs = options
-            .into_query_string()
-            .with_value("start", start)
-            .with_value("end", end);
-        let url = format!("{BASE_URL}users/{user}/summaries{qs}", user = self.user);
-        let response = self.client.get(url).send().await?;
-        Self::deserialize_as(response, |r| r).await
+        self.execute(endpoint::summaries_for_range(&self.user, range, options))
+            .await
     }
 
-    async fn deserialize_as<TIn, F, TOut>(response: Response, map: F) -> Result<TOut, ApiError>
+    /// Fetches the summaries of every day in the given range.
+    ///
+    /// ## Documentation
+    /// * [Summaries](https://wakatime.com/developers#summaries)
+    #[cfg(feature = "time")]
+    pub async fn summaries_in<'a>(
+        &self,
+        range: DateRange,
+        options: SummariesOptions<'a>,
+    ) -> Result<model::Summaries, ApiError> {
+        self.summaries(range.start(), range.end(), options).await
+    }
+
+    /// Fetches the profile of the user, including the timezone and keystroke timeout
+    /// used for calculating their coding activity.
+    ///
+    /// ## Documentation
+    /// * [Users](https://wakatime.com/developers#users)
+    pub async fn user(&self) -> Result<model::user::User, ApiError> {
+        self.execute(endpoint::user(&self.user)).await
+    }
+
+    /// Lists the plugins the user sent heartbeats with, including their editor and version.
+    ///
+    /// ## Documentation
+    /// * [User Agents](https://wakatime.com/developers#user_agents)
+    pub async fn user_agents(&self) -> Result<Vec<model::user_agents::UserAgent>, ApiError> {
+        self.execute(endpoint::user_agents(&self.user)).await
+    }
+
+    /// Walks all pages of a paginated endpoint, starting at the first page.
+    ///
+    /// The `fetch` function is called with the client and the number of each page to fetch.
+    /// Pages are fetched lazily as the stream is consumed; after the first error, the stream ends.
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use waka::{CommitsOptions, PaginationOptions, WakaTimeClient};
+    ///
+    /// # async fn test(client: WakaTimeClient) -> Result<(), waka::ApiError> {
+    /// let commits = client.paginate(PaginationOptions::default(), |client, page| {
+    ///     let options = CommitsOptions {
+    ///         page: Some(page),
+    ///         ..Default::default()
+    ///     };
+    ///     client.commits("waka-rs", options)
+    /// });
+    ///
+    /// futures_util::pin_mut!(commits);
+    /// while let Some(commit) = commits.next().await {
+    ///     println!("{}", commit?.hash);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn paginate<'a, F, Fut, P>(
+        &'a self,
+        options: PaginationOptions,
+        fetch: F,
+    ) -> impl Stream<Item = Result<P::Item, ApiError>> + 'a
     where
-        TIn: for<'de> Deserialize<'de>,
-        F: FnOnce(TIn) -> TOut,
+        F: FnMut(&'a Self, u32) -> Fut + 'a,
+        Fut: Future<Output = Result<P, ApiError>> + 'a,
+        P: Paginated + 'a,
     {
-        match response.status().as_u16() {
-            200 => match response.json::<TIn>().await {
-                Ok(response) => Ok(map(response)),
-                Err(e) => Err(ApiError::InvalidFormat(e)),
-            },
-            other => {
-                let errors = response.json::<ErrorsResponse>().await.ok();
-                match other {
-                    401 => Err(ApiError::Unauthorized(errors)),
-                    other => Err(ApiError::Unspecified(other, errors)),
+        let state = PaginationState::new(fetch, options);
+        futures_util::stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(item) = state.items.pop_front() {
+                    return Some((Ok(item), state));
+                }
+
+                let page = state.page_to_fetch()?;
+                match (state.fetch)(self, page).await {
+                    Ok(page) => state.push_page(page),
+                    Err(e) => {
+                        state.stop();
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+        })
+    }
+
+    /// Executes the request of an endpoint, polling it while its data is not up to date
+    /// if enabled via [`WakaTimeClientBuilder::with_polling`].
+    async fn execute<T>(&self, endpoint: Endpoint<T>) -> Result<T, ApiError> {
+        let mut poll = self
+            .polling
+            .as_ref()
+            .filter(|_| endpoint.may_be_calculating)
+            .map(Poll::new);
+
+        loop {
+            let result = self.send(&endpoint).await;
+            if let Err(ApiError::NotUpToDate { .. }) = result {
+                if let Some(interval) = poll.as_mut().and_then(Poll::next_interval) {
+                    self.transport.sleep(interval).await;
+                    continue;
+                }
+            }
+            return result;
+        }
+    }
+
+    /// Sends the request of an endpoint, retrying it according to the [`RetryPolicy`].
+    async fn send<T>(&self, endpoint: &Endpoint<T>) -> Result<T, ApiError> {
+        endpoint.check_scope(self.scopes.as_deref())?;
+        let request = endpoint.to_request(&self.base_url, &self.headers);
+        let mut attempts = 1;
+        loop {
+            let result = self.transport.send(request.clone()).await;
+            match endpoint.outcome(&request, result, &self.retry_policy, attempts) {
+                Outcome::Done(result) => return result,
+                Outcome::Retry(backoff) => {
+                    self.transport.sleep(backoff).await;
+                    attempts += 1;
                 }
             }
         }
@@ -162,6 +746,59 @@ pub struct DataWrapper<T> {
     data: T,
 }
 
+/// The state of [`WakaTimeClient::paginate`].
+struct PaginationState<F, T> {
+    /// The function fetching a page.
+    fetch: F,
+    /// The items of the current page not yet yielded.
+    items: VecDeque<T>,
+    /// The next page to fetch, if any.
+    next_page: Option<u32>,
+    /// The number of pages fetched so far.
+    pages_fetched: u32,
+    /// The maximum number of pages to fetch, if limited.
+    max_pages: Option<u32>,
+}
+
+impl<F, T> PaginationState<F, T> {
+    fn new(fetch: F, options: PaginationOptions) -> Self {
+        Self {
+            fetch,
+            items: VecDeque::new(),
+            next_page: Some(1),
+            pages_fetched: 0,
+            max_pages: options.max_pages,
+        }
+    }
+
+    /// Returns the number of the next page, unless all pages were fetched or the limit was reached.
+    fn page_to_fetch(&self) -> Option<u32> {
+        if self.max_pages.is_some_and(|max| self.pages_fetched >= max) {
+            return None;
+        }
+        self.next_page
+    }
+
+    /// Queues the items of a fetched page.
+    fn push_page<P: Paginated<Item = T>>(&mut self, page: P) {
+        self.pages_fetched += 1;
+        self.next_page = page.page_info().next();
+        self.items.extend(page.into_items());
+    }
+
+    /// Ends the pagination, e.g. after a page could not be fetched.
+    fn stop(&mut self) {
+        self.next_page = None;
+    }
+}
+
+/// Options for walking paginated endpoints, see [`WakaTimeClient::paginate`].
+#[derive(Debug, Default, Clone)]
+pub struct PaginationOptions {
+    /// The maximum number of pages to fetch; if unspecified, all pages are fetched.
+    pub max_pages: Option<u32>,
+}
+
 trait IntoQueryString {
     fn into_query_string(self) -> QueryString;
 }
@@ -188,6 +825,188 @@ impl<'a> IntoQueryString for CommitOptions<'a> {
     }
 }
 
+#[derive(Debug, Default, Clone)]
+pub struct CommitsOptions<'a> {
+    /// Filter commits to only those authored by the given username.
+    pub author: Option<&'a str>,
+    /// Filter commits to a branch; defaults to the repo's default branch name.
+    pub branch: Option<&'a str>,
+    /// The page to fetch, starting at `1`.
+    pub page: Option<u32>,
+}
+
+impl<'a> IntoQueryString for CommitsOptions<'a> {
+    fn into_query_string(self) -> QueryString {
+        QueryString::new()
+            .with_opt_value("author", self.author)
+            .with_opt_value("branch", self.branch)
+            .with_opt_value("page", self.page.map(|v| v.to_string()))
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DurationsOptions<'a> {
+    pub project: Option<&'a str>,
+    pub branches: Option<&'a str>,
+    pub timeout: Option<u32>,
+    pub writes_only: Option<bool>,
+    pub timezone: Option<&'a str>,
+    pub slice_by: Option<DurationsSliceBy>,
+}
+
+impl<'a> IntoQueryString for DurationsOptions<'a> {
+    fn into_query_string(self) -> QueryString {
+        QueryString::new()
+            .with_opt_value("project", self.project)
+            .with_opt_value("branches", self.branches)
+            .with_opt_value("timeout", self.timeout.map(|v| v.to_string()))
+            .with_opt_value("writes_only", self.writes_only.map(|v| v.to_string()))
+            .with_opt_value("timezone", self.timezone)
+            .with_opt_value("slice_by", self.slice_by.map(|v| v.as_str()))
+    }
+}
+
+/// The primary key by which [`WakaTimeClient::durations`] are sliced.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum DurationsSliceBy {
+    #[default]
+    Entity,
+    Language,
+    Dependencies,
+    Os,
+    Editor,
+    Category,
+    Machine,
+}
+
+impl DurationsSliceBy {
+    /// Returns the value as used in the query string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Entity => "entity",
+            Self::Language => "language",
+            Self::Dependencies => "dependencies",
+            Self::Os => "os",
+            Self::Editor => "editor",
+            Self::Category => "category",
+            Self::Machine => "machine",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct LeadersOptions<'a> {
+    /// Filter leaders by a language, for ex: `Rust`.
+    pub language: Option<&'a str>,
+    /// Filter leaders to those open to job offers.
+    pub is_hireable: Option<bool>,
+    /// Filter leaders by a two letter country code, for ex: `US`.
+    pub country_code: Option<&'a str>,
+    /// The page to fetch, starting at `1`.
+    pub page: Option<u32>,
+}
+
+impl<'a> IntoQueryString for LeadersOptions<'a> {
+    fn into_query_string(self) -> QueryString {
+        QueryString::new()
+            .with_opt_value("language", self.language)
+            .with_opt_value("is_hireable", self.is_hireable.map(|v| v.to_string()))
+            .with_opt_value("country_code", self.country_code)
+            .with_opt_value("page", self.page.map(|v| v.to_string()))
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ProjectsOptions<'a> {
+    /// Filter project names by a search term.
+    pub q: Option<&'a str>,
+}
+
+impl<'a> IntoQueryString for ProjectsOptions<'a> {
+    fn into_query_string(self) -> QueryString {
+        QueryString::new().with_opt_value("q", self.q)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct StatsOptions {
+    pub timeout: Option<u32>,
+    pub writes_only: Option<bool>,
+}
+
+impl IntoQueryString for StatsOptions {
+    fn into_query_string(self) -> QueryString {
+        QueryString::new()
+            .with_opt_value("timeout", self.timeout.map(|v| v.to_string()))
+            .with_opt_value("writes_only", self.writes_only.map(|v| v.to_string()))
+    }
+}
+
+/// The time range of [`WakaTimeClient::stats`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StatsRange {
+    Last7Days,
+    Last30Days,
+    Last6Months,
+    LastYear,
+    AllTime,
+    /// A calendar year, e.g. `2023`.
+    Year(u16),
+    /// A calendar month, e.g. `2023-01`; see [`StatsRange::month`].
+    Month(YearMonth),
+}
+
+impl StatsRange {
+    /// Creates the range of a calendar month, given as year and month (`1` to `12`),
+    /// or `None` if the month is out of range.
+    pub fn month(year: u16, month: u8) -> Option<Self> {
+        YearMonth::new(year, month).map(Self::Month)
+    }
+}
+
+/// A calendar month of a [`StatsRange`], where the month is always between `1` and `12`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct YearMonth {
+    year: u16,
+    month: u8,
+}
+
+impl YearMonth {
+    /// Creates the month of a year, or `None` if `month` is not between `1` and `12`.
+    pub fn new(year: u16, month: u8) -> Option<Self> {
+        (1..=12).contains(&month).then_some(Self { year, month })
+    }
+
+    /// Gets the year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Gets the month, between `1` and `12`.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+}
+
+impl Display for StatsRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Last7Days => write!(f, "last_7_days"),
+            Self::Last30Days => write!(f, "last_30_days"),
+            Self::Last6Months => write!(f, "last_6_months"),
+            Self::LastYear => write!(f, "last_year"),
+            Self::AllTime => write!(f, "all_time"),
+            Self::Year(year) => write!(f, "{year:04}"),
+            Self::Month(month) => write!(
+                f,
+                "{year:04}-{month:02}",
+                year = month.year,
+                month = month.month
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct SummariesOptions<'a> {
     pub project: Option<&'a str>,
@@ -195,7 +1014,9 @@ pub struct SummariesOptions<'a> {
     pub timeout: Option<u32>,
     pub writes_only: Option<bool>,
     pub timezone: Option<&'a str>,
-    pub range: Option<&'a str>,
+    /// A preset used instead of explicit start and end dates,
+    /// see [`WakaTimeClient::summaries_for_range`].
+    pub range: Option<SummariesRange>,
 }
 
 impl<'a> IntoQueryString for SummariesOptions<'a> {
@@ -206,6 +1027,238 @@ impl<'a> IntoQueryString for SummariesOptions<'a> {
             .with_opt_value("timeout", self.timeout.map(|v| v.to_string()))
             .with_opt_value("writes_only", self.writes_only.map(|v| v.to_string()))
             .with_opt_value("timezone", self.timezone)
-            .with_opt_value("range", self.range)
+            .with_opt_value("range", self.range.map(|v| v.as_str()))
+    }
+}
+
+/// A preset time range of [`WakaTimeClient::summaries_for_range`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SummariesRange {
+    Today,
+    Yesterday,
+    Last7Days,
+    Last7DaysFromYesterday,
+    Last14Days,
+    Last30Days,
+    ThisWeek,
+    LastWeek,
+    ThisMonth,
+    LastMonth,
+}
+
+impl SummariesRange {
+    /// Returns the value as used in the query string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Today => "Today",
+            Self::Yesterday => "Yesterday",
+            Self::Last7Days => "Last 7 Days",
+            Self::Last7DaysFromYesterday => "Last 7 Days from Yesterday",
+            Self::Last14Days => "Last 14 Days",
+            Self::Last30Days => "Last 30 Days",
+            Self::ThisWeek => "This Week",
+            Self::LastWeek => "Last Week",
+            Self::ThisMonth => "This Month",
+            Self::LastMonth => "Last Month",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::page::PageInfo;
+    use crate::model::Page;
+    use crate::testing::FixtureTransport;
+    use crate::transport::Method;
+    use futures_util::StreamExt;
+    use std::time::Duration;
+
+    /// Builds a page of the members of an organization's dashboard with the given ids.
+    fn members_page(page: u32, total_pages: u32, next_page: Option<u32>, ids: &[&str]) -> String {
+        let data = ids
+            .iter()
+            .map(|id| format!(r#"{{"id":"{id}"}}"#))
+            .collect::<Vec<_>>()
+            .join(",");
+        let next_page = next_page.map_or("null".to_string(), |next| next.to_string());
+        format!(
+            r#"{{"data":[{data}],"page":{page},"total_pages":{total_pages},"next_page":{next_page},"prev_page":null}}"#
+        )
+    }
+
+    async fn members(transport: &FixtureTransport) -> Vec<Result<String, ApiError>> {
+        let client = WakaTimeClientBuilder::new_with_api_key("waka_test")
+            .with_transport(transport.clone())
+            .build()
+            .unwrap();
+        client
+            .org_dashboard_members_stream("org", "dashboard", PaginationOptions::default())
+            .map(|member| member.map(|m| m.id))
+            .collect()
+            .await
+    }
+
+    fn queries(transport: &FixtureTransport) -> Vec<String> {
+        transport
+            .requests()
+            .iter()
+            .map(|r| r.query().unwrap_or_default().to_string())
+            .collect()
+    }
+
+    fn page(page: u32, total_pages: u32, next_page: Option<u32>) -> Page<u32> {
+        Page {
+            data: vec![page],
+            page: PageInfo {
+                page,
+                total_pages,
+                total: None,
+                next_page,
+                prev_page: None,
+            },
+        }
+    }
+
+    #[test]
+    fn stats_month_is_validated() {
+        assert_eq!(StatsRange::month(2023, 1).unwrap().to_string(), "2023-01");
+        assert_eq!(StatsRange::month(2023, 12).unwrap().to_string(), "2023-12");
+        assert_eq!(StatsRange::month(2023, 0), None);
+        assert_eq!(StatsRange::month(2023, 13), None);
+    }
+
+    #[test]
+    fn dot_user_is_rejected() {
+        let result = WakaTimeClientBuilder::new_with_api_key("waka_test")
+            .with_user("..")
+            .build();
+        assert!(matches!(result, Err(BuilderError::InvalidUser(user)) if user == ".."));
+    }
+
+    fn millis(durations: &[u64]) -> Vec<Duration> {
+        durations
+            .iter()
+            .copied()
+            .map(Duration::from_millis)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn not_up_to_date_data_is_polled() {
+        let transport = FixtureTransport::new();
+        let client = WakaTimeClientBuilder::new_with_api_key("waka_test")
+            .with_transport(transport.clone())
+            .with_polling(PollingPolicy {
+                timeout: Duration::from_millis(10),
+                initial_interval: Duration::from_millis(1),
+                max_interval: Duration::from_millis(2),
+            })
+            .build()
+            .unwrap();
+
+        transport.enqueue_status(202);
+        transport.enqueue_status(202);
+        let stats = client.stats(StatsRange::Last7Days, StatsOptions::default());
+        assert!(stats.await.is_ok());
+        assert_eq!(transport.requests().len(), 3);
+
+        assert_eq!(transport.sleeps(), millis(&[1, 2]));
+
+        // Gives up as the next wait would exceed the timeout.
+        transport.respond_with_status(Method::Get, "users/*/stats/*", 202);
+        let stats = client.stats(StatsRange::Last7Days, StatsOptions::default());
+        assert!(matches!(
+            stats.await,
+            Err(ApiError::NotUpToDate {
+                percent_calculated: Some(50)
+            })
+        ));
+        assert_eq!(transport.requests().len(), 3 + 6);
+        assert_eq!(transport.sleeps(), millis(&[1, 2, 1, 2, 2, 2, 2]));
+    }
+
+    #[tokio::test]
+    async fn not_up_to_date_data_is_reported_without_polling() {
+        let transport = FixtureTransport::new();
+        transport.enqueue_status(202);
+        let client = WakaTimeClientBuilder::new_with_api_key("waka_test")
+            .with_transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let stats = client.stats(StatsRange::Last7Days, StatsOptions::default());
+        assert!(matches!(stats.await, Err(ApiError::NotUpToDate { .. })));
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[test]
+    fn pagination_state_walks_the_pages() {
+        // Follows `next_page` where reported, falling back to `total_pages`.
+        let mut state = PaginationState::new((), PaginationOptions::default());
+        assert_eq!(state.page_to_fetch(), Some(1));
+        state.push_page(page(1, 3, Some(2)));
+        assert_eq!(state.page_to_fetch(), Some(2));
+        state.push_page(page(2, 3, None));
+        assert_eq!(state.page_to_fetch(), Some(3));
+        state.push_page(page(3, 3, None));
+        assert_eq!(state.page_to_fetch(), None);
+        assert_eq!(state.items, [1, 2, 3]);
+
+        // Ends at `max_pages`.
+        let options = PaginationOptions { max_pages: Some(1) };
+        let mut state = PaginationState::new((), options);
+        state.push_page(page(1, 3, Some(2)));
+        assert_eq!(state.page_to_fetch(), None);
+
+        // Ends if `next_page` does not advance.
+        let mut state = PaginationState::new((), PaginationOptions::default());
+        state.push_page(page(2, 3, Some(2)));
+        assert_eq!(state.page_to_fetch(), None);
+
+        // Ends after an error.
+        let mut state = PaginationState::<_, u32>::new((), PaginationOptions::default());
+        state.stop();
+        assert_eq!(state.page_to_fetch(), None);
+    }
+
+    #[tokio::test]
+    async fn paginate_follows_next_page() {
+        let transport = FixtureTransport::new();
+        transport.enqueue(200, members_page(1, 3, Some(2), &["a", "b"]));
+        transport.enqueue(200, members_page(2, 3, Some(3), &["c"]));
+        transport.enqueue(200, members_page(3, 3, None, &["d"]));
+
+        let ids = members(&transport).await;
+        let ids = ids.into_iter().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(ids, ["a", "b", "c", "d"]);
+        assert_eq!(queries(&transport), ["page=1", "page=2", "page=3"]);
+    }
+
+    #[tokio::test]
+    async fn paginate_stops_if_next_page_does_not_advance() {
+        let transport = FixtureTransport::new();
+        transport.enqueue(200, members_page(1, 3, Some(2), &["a"]));
+        transport.enqueue(200, members_page(2, 3, Some(2), &["b"]));
+        transport.enqueue(200, members_page(2, 3, Some(3), &["c"]));
+
+        let ids = members(&transport).await;
+        let ids = ids.into_iter().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(ids, ["a", "b"]);
+        assert_eq!(queries(&transport), ["page=1", "page=2"]);
+    }
+
+    #[tokio::test]
+    async fn paginate_stops_after_an_error() {
+        let transport = FixtureTransport::new();
+        transport.enqueue(200, members_page(1, 3, Some(2), &["a"]));
+        transport.enqueue_status(404);
+        transport.enqueue(200, members_page(3, 3, None, &["c"]));
+
+        let results = members(&transport).await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_deref().unwrap(), "a");
+        assert!(matches!(results[1], Err(ApiError::NotFound(_))));
+        assert_eq!(queries(&transport), ["page=1", "page=2"]);
     }
 }
@@ -38,3 +38,26 @@ pub struct AllTimeSinceTodayRange {
     /// Timezone used in Olson Country/Region format.
     pub timezone: String,
 }
+
+#[cfg(feature = "time")]
+impl AllTimeSinceTodayRange {
+    /// Parses [`start`](Self::start) as a datetime.
+    pub fn parsed_start(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.start)
+    }
+
+    /// Parses [`start_date`](Self::start_date) as a calendar date.
+    pub fn parsed_start_date(&self) -> Result<time::Date, time::error::Parse> {
+        crate::date::parse_date(&self.start_date)
+    }
+
+    /// Parses [`end`](Self::end) as a datetime.
+    pub fn parsed_end(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.end)
+    }
+
+    /// Parses [`end_date`](Self::end_date) as a calendar date.
+    pub fn parsed_end_date(&self) -> Result<time::Date, time::error::Parse> {
+        crate::date::parse_date(&self.end_date)
+    }
+}
@@ -1,5 +1,7 @@
-//! Contains the models for [`WakaTimeClient::commit`](crate::WakaTimeClient::commit).
+//! Contains the models for [`WakaTimeClient::commit`](crate::WakaTimeClient::commit)
+//! and [`WakaTimeClient::commits`](crate::WakaTimeClient::commits).
 
+use crate::model::page::{PageInfo, Paginated};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,6 +14,37 @@ pub struct Commits {
     pub status: String,
 }
 
+/// A single page of commits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitsPage {
+    pub commits: Vec<Commit>,
+    /// Author username the commits were filtered by.
+    pub author: Option<String>,
+    /// Branch name the commits were filtered by.
+    pub branch: Option<String>,
+    pub project: Project,
+    /// Project's sync status.
+    pub status: String,
+    #[serde(flatten)]
+    pub page: PageInfo,
+    /// API url of the next page, if there is one.
+    pub next_page_url: Option<String>,
+    /// API url of the previous page, if there is one.
+    pub prev_page_url: Option<String>,
+}
+
+impl Paginated for CommitsPage {
+    type Item = Commit;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.commits
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Commit {
     /// URL of author's avatar image.
@@ -68,6 +101,24 @@ pub struct Commit {
     pub url: String,
 }
 
+#[cfg(feature = "time")]
+impl Commit {
+    /// Parses [`author_date`](Self::author_date) as a datetime.
+    pub fn parsed_author_date(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.author_date)
+    }
+
+    /// Parses [`committer_date`](Self::committer_date) as a datetime.
+    pub fn parsed_committer_date(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.committer_date)
+    }
+
+    /// Parses [`created_at`](Self::created_at) as a datetime.
+    pub fn parsed_created_at(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.created_at)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
     /// Unique id of project.
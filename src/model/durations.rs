@@ -0,0 +1,56 @@
+//! Contains the models for [`WakaTimeClient::durations`](crate::WakaTimeClient::durations)
+//! and [`WakaTimeClient::org_dashboard_member_durations`](crate::WakaTimeClient::org_dashboard_member_durations).
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Durations {
+    pub data: Vec<Duration>,
+    /// List of branches with activity on this day; included only when project url parameter used.
+    pub branches: Option<Vec<String>>,
+    /// Start of time range as ISO 8601 UTC datetime.
+    pub start: String,
+    /// End of time range as ISO 8601 UTC datetime.
+    pub end: String,
+    /// Timezone used for this request in Olson Country/Region format.
+    pub timezone: String,
+}
+
+#[cfg(feature = "time")]
+impl Durations {
+    /// Parses [`start`](Self::start) as a datetime.
+    pub fn parsed_start(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.start)
+    }
+
+    /// Parses [`end`](Self::end) as a datetime.
+    pub fn parsed_end(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.end)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Duration {
+    /// Project name.
+    pub project: Option<String>,
+    /// Start of this duration as UNIX epoch; numbers after decimal point are fractions of a second.
+    pub time: f64,
+    /// Length of time of this duration in seconds.
+    pub duration: f64,
+    /// Color of this project as hex string, if set.
+    pub color: Option<String>,
+    /// Entity name; included when sliced by `entity`.
+    pub entity: Option<String>,
+    /// Language name; included when sliced by `language`.
+    pub language: Option<String>,
+    /// Dependency names; included when sliced by `dependencies`.
+    pub dependencies: Option<Vec<String>>,
+    /// OS name; included when sliced by `os`.
+    pub os: Option<String>,
+    /// Editor name; included when sliced by `editor`.
+    pub editor: Option<String>,
+    /// Category name, for ex: Coding or Debugging; included when sliced by `category`.
+    pub category: Option<String>,
+    /// Unique id of the machine; included when sliced by `machine`.
+    pub machine_name_id: Option<String>,
+}
@@ -0,0 +1,171 @@
+//! Contains the models for [`WakaTimeClient::goals`](crate::WakaTimeClient::goals)
+//! and [`WakaTimeClient::goal`](crate::WakaTimeClient::goal).
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Goals {
+    pub data: Vec<Goal>,
+    /// Total number of goals.
+    pub total: u32,
+    /// Number of pages of goals.
+    pub total_pages: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Goal {
+    /// Unique id of this goal.
+    pub id: String,
+    /// Title of this goal.
+    pub title: String,
+    /// Custom title of this goal, if set by the owner.
+    pub custom_title: Option<String>,
+    /// Type of this goal, for ex: `coding`.
+    pub r#type: String,
+    /// Period of this goal; one of `day` or `week`.
+    pub delta: String,
+    /// Goal amount in seconds per delta.
+    pub seconds: u32,
+    /// Percent by which the goal is increased each period, if any.
+    pub improve_by_percent: Option<f32>,
+    /// Average status over all periods of this goal.
+    pub average_status: Option<GoalStatus>,
+    /// Cumulative status over all periods of this goal.
+    pub cumulative_status: Option<GoalStatus>,
+    /// Status of the current period of this goal.
+    pub status: Option<GoalStatus>,
+    /// A number between 0 and 100 where 100 means the goal progress is up to date.
+    pub status_percent_calculated: Option<u8>,
+    /// Progress of this goal over the recent periods.
+    pub chart_data: Option<Vec<GoalChartData>>,
+    /// Days of the week which are not counted towards this goal, for ex: `saturday`.
+    pub ignore_days: Vec<String>,
+    /// Whether days without any coding activity are ignored.
+    pub ignore_zero_days: bool,
+    /// Whether this goal is met by staying below the target instead of reaching it.
+    pub is_inverse: bool,
+    /// Whether this goal is enabled.
+    pub is_enabled: bool,
+    /// Whether this goal is currently snoozed.
+    pub is_snoozed: bool,
+    /// Whether progress of this goal is tweeted.
+    pub is_tweeting: bool,
+    /// Whether the current user owns this goal.
+    pub is_current_user_owner: bool,
+    /// Time until which this goal is snoozed in ISO 8601 format.
+    pub snooze_until: Option<String>,
+    /// Languages counted towards this goal; empty if all languages are counted.
+    pub languages: Vec<String>,
+    /// Editors counted towards this goal; empty if all editors are counted.
+    pub editors: Vec<String>,
+    /// Projects counted towards this goal; empty if all projects are counted.
+    pub projects: Vec<String>,
+    /// Time range of this goal in human readable format.
+    pub range_text: String,
+    pub owner: GoalUser,
+    pub subscribers: Vec<GoalSubscriber>,
+    pub shared_with: Vec<GoalSharedWith>,
+    /// Time when this goal was created in ISO 8601 format.
+    pub created_at: String,
+    /// Time when this goal was last modified in ISO 8601 format.
+    pub modified_at: Option<String>,
+}
+
+/// The status of a goal, or of a single period of a goal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GoalStatus {
+    Success,
+    Fail,
+    Ignored,
+    Pending,
+    /// A status not known to this client.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalChartData {
+    /// Actual coding activity in seconds for this period.
+    pub actual_seconds: f64,
+    /// Actual coding activity for this period in human readable format.
+    pub actual_seconds_text: String,
+    /// Goal amount in seconds for this period.
+    pub goal_seconds: f64,
+    /// Goal amount for this period in human readable format.
+    pub goal_seconds_text: String,
+    pub range: GoalChartRange,
+    /// Status of this period.
+    pub range_status: GoalStatus,
+    /// Explanation of the status of this period in human readable format.
+    pub range_status_reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalChartRange {
+    /// This day as Date string in YEAR-MONTH-DAY format; only set when delta is `day`.
+    pub date: Option<String>,
+    /// Start of this period as ISO 8601 UTC datetime.
+    pub start: String,
+    /// End of this period as ISO 8601 UTC datetime.
+    pub end: String,
+    /// This period in human-readable format relative to the current day.
+    pub text: String,
+    /// Timezone used in Olson Country/Region format.
+    pub timezone: String,
+}
+
+#[cfg(feature = "time")]
+impl GoalChartRange {
+    /// Parses [`start`](Self::start) as a datetime.
+    pub fn parsed_start(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.start)
+    }
+
+    /// Parses [`end`](Self::end) as a datetime.
+    pub fn parsed_end(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.end)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalUser {
+    /// Unique id of this user.
+    pub id: String,
+    /// Public display name of this user.
+    pub display_name: Option<String>,
+    /// Full name of this user.
+    pub full_name: Option<String>,
+    /// Public email address of this user.
+    pub email: Option<String>,
+    /// Public username of this user.
+    pub username: Option<String>,
+    /// URL of this user's avatar image.
+    pub photo: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalSubscriber {
+    /// Unique id of the subscribed user.
+    pub user_id: String,
+    /// Email address of the subscribed user, if visible.
+    pub email: Option<String>,
+    /// How often the subscriber is notified by email, for ex: `daily` or `weekly`.
+    pub email_frequency: Option<String>,
+    /// Full name of the subscribed user.
+    pub full_name: Option<String>,
+    /// Public username of the subscribed user.
+    pub username: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalSharedWith {
+    /// Unique id of the user this goal is shared with.
+    pub user_id: Option<String>,
+    /// Email address this goal is shared with.
+    pub email: Option<String>,
+    /// Public username of the user this goal is shared with.
+    pub username: Option<String>,
+    /// Status of the invitation, for ex: `pending` or `accepted`.
+    pub status: Option<String>,
+}
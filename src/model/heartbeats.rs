@@ -0,0 +1,176 @@
+//! Contains the models for [`WakaTimeClient::heartbeats`](crate::WakaTimeClient::heartbeats).
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Heartbeats {
+    pub data: Vec<Heartbeat>,
+    /// Start of time range as ISO 8601 UTC datetime.
+    pub start: String,
+    /// End of time range as ISO 8601 UTC datetime.
+    pub end: String,
+    /// Timezone used for this request in Olson Country/Region format.
+    pub timezone: String,
+}
+
+#[cfg(feature = "time")]
+impl Heartbeats {
+    /// Parses [`start`](Self::start) as a datetime.
+    pub fn parsed_start(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.start)
+    }
+
+    /// Parses [`end`](Self::end) as a datetime.
+    pub fn parsed_end(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.end)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Heartbeat {
+    /// Unique id of this heartbeat.
+    pub id: String,
+    /// Entity heartbeat is logging time against, such as an absolute file path or domain.
+    pub entity: String,
+    /// Type of entity; can be `file`, `domain` or `app`.
+    pub r#type: String,
+    /// Category for this activity, for ex: `coding` or `debugging`.
+    pub category: Option<String>,
+    /// UNIX epoch timestamp; numbers after decimal point are fractions of a second.
+    pub time: f64,
+    /// Project name.
+    pub project: Option<String>,
+    /// Branch name.
+    pub branch: Option<String>,
+    /// Language name.
+    pub language: Option<String>,
+    /// Dependencies detected from entity file, such as imported packages.
+    pub dependencies: Option<Vec<String>>,
+    /// Total number of lines in the entity (when entity type is file).
+    pub lines: Option<u32>,
+    /// Current line row number of cursor.
+    pub lineno: Option<u32>,
+    /// Current cursor column position.
+    pub cursorpos: Option<u32>,
+    /// Whether this heartbeat was triggered from writing to a file.
+    pub is_write: Option<bool>,
+    /// Unique id of the machine which sent this heartbeat, see
+    /// [`WakaTimeClient::machine_names`](crate::WakaTimeClient::machine_names).
+    pub machine_name_id: Option<String>,
+    /// Unique id of the user agent which sent this heartbeat, see
+    /// [`WakaTimeClient::user_agents`](crate::WakaTimeClient::user_agents).
+    pub user_agent_id: Option<String>,
+    /// Time when heartbeat was created in ISO 8601 format.
+    pub created_at: Option<String>,
+}
+
+/// A heartbeat to send via [`WakaTimeClient::send_heartbeat`](crate::WakaTimeClient::send_heartbeat)
+/// or [`WakaTimeClient::send_heartbeats_bulk`](crate::WakaTimeClient::send_heartbeats_bulk).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NewHeartbeat {
+    /// Entity heartbeat is logging time against, such as an absolute file path or domain.
+    pub entity: String,
+    /// Type of entity; can be `file`, `domain` or `app`.
+    pub r#type: String,
+    /// Category for this activity, for ex: `coding` or `debugging`; defaults to `coding`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// UNIX epoch timestamp; numbers after decimal point are fractions of a second.
+    pub time: f64,
+    /// Project name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// Count of the number of folders in the project root path (optional); for ex: if the project
+    /// folder is `/Users/user/projects/wakatime` and the entity path is
+    /// `/Users/user/projects/wakatime/models/user.py` then the `project_root_count` is `5`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_root_count: Option<u32>,
+    /// Branch name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Language name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Comma separated list of dependencies detected from entity file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<String>,
+    /// Total number of lines in the entity (when entity type is file).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lines: Option<u32>,
+    /// Number of lines added since last heartbeat in the current file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_additions: Option<u32>,
+    /// Number of lines removed since last heartbeat in the current file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_deletions: Option<u32>,
+    /// Current line row number of cursor with the first line starting at 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lineno: Option<u32>,
+    /// Current cursor column position starting from 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursorpos: Option<u32>,
+    /// Whether this heartbeat was triggered from writing to a file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_write: Option<bool>,
+}
+
+/// A heartbeat as acknowledged by the server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedHeartbeat {
+    /// Unique id of the newly created heartbeat.
+    pub id: String,
+    /// Entity heartbeat is logging time against, such as an absolute file path or domain.
+    pub entity: String,
+    /// Type of entity; can be `file`, `domain` or `app`.
+    pub r#type: String,
+    /// UNIX epoch timestamp; numbers after decimal point are fractions of a second.
+    pub time: f64,
+}
+
+/// The response of [`WakaTimeClient::send_heartbeats_bulk`](crate::WakaTimeClient::send_heartbeats_bulk)
+/// as returned by the server, with one entry per heartbeat.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkHeartbeatsResponse {
+    pub responses: Vec<(BulkHeartbeatBody, u16)>,
+}
+
+/// The body of a single entry in a [`BulkHeartbeatsResponse`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkHeartbeatBody {
+    /// The created heartbeat, if it was accepted.
+    pub data: Option<CreatedHeartbeat>,
+    /// The reason the heartbeat was rejected, if any.
+    pub error: Option<String>,
+}
+
+/// The outcome of sending a single heartbeat in a bulk request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkHeartbeatResult {
+    /// The HTTP status code for this heartbeat, e.g. `201` if it was created.
+    pub status: u16,
+    /// The created heartbeat, if it was accepted.
+    pub data: Option<CreatedHeartbeat>,
+    /// The reason the heartbeat was rejected, if any.
+    pub error: Option<String>,
+}
+
+impl BulkHeartbeatResult {
+    /// Indicates whether the heartbeat was accepted by the server.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+impl From<BulkHeartbeatsResponse> for Vec<BulkHeartbeatResult> {
+    fn from(value: BulkHeartbeatsResponse) -> Self {
+        value
+            .responses
+            .into_iter()
+            .map(|(body, status)| BulkHeartbeatResult {
+                status,
+                data: body.data,
+                error: body.error,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,187 @@
+//! Contains the models for [`WakaTimeClient::leaders`](crate::WakaTimeClient::leaders),
+//! [`WakaTimeClient::private_leaderboards`](crate::WakaTimeClient::private_leaderboards)
+//! and [`WakaTimeClient::private_leaderboard_leaders`](crate::WakaTimeClient::private_leaderboard_leaders).
+
+use crate::model::page::{PageInfo, Paginated};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Leaders {
+    /// The leaders on this page, ordered by rank.
+    pub data: Vec<Leader>,
+    /// The rank of the current user, if authenticated.
+    pub current_user: Option<CurrentUserRank>,
+    /// The language the leaders were filtered by, if any.
+    pub language: Option<String>,
+    /// The range of the leaderboard, usually the last 7 days.
+    pub range: LeadersRange,
+    /// Keystroke timeout used for the leaderboard in minutes.
+    pub timeout: Option<u32>,
+    /// Whether only file writes were counted as coding activity.
+    pub writes_only: Option<bool>,
+    /// Time when the leaderboard was last updated in ISO 8601 format.
+    pub modified_at: Option<String>,
+    #[serde(flatten)]
+    pub page: PageInfo,
+}
+
+impl Paginated for Leaders {
+    type Item = Leader;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.data
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Leader {
+    /// Rank of this user on the leaderboard, starting at `1`.
+    pub rank: u32,
+    /// Coding activity of this user within the range of the leaderboard.
+    pub running_total: LeaderRunningTotal,
+    /// The user holding this rank.
+    pub user: LeaderUser,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrentUserRank {
+    /// Rank of the current user, if ranked.
+    pub rank: Option<u32>,
+    /// Page of the leaderboard containing the current user, if ranked.
+    pub page: Option<u32>,
+    /// The current user.
+    pub user: Option<LeaderUser>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderRunningTotal {
+    /// Total coding activity in seconds.
+    pub total_seconds: f64,
+    /// Total coding activity in human readable format.
+    pub human_readable_total: String,
+    /// Average coding activity per day in seconds.
+    pub daily_average: f64,
+    /// Average coding activity per day in human readable format.
+    pub human_readable_daily_average: String,
+    /// Languages used, ordered by coding activity.
+    pub languages: Vec<LeaderLanguage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderLanguage {
+    /// Name of the language, for ex: `Rust`.
+    pub name: String,
+    /// Coding activity in this language in seconds.
+    pub total_seconds: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderUser {
+    /// Unique id of the user.
+    pub id: String,
+    /// Email address of the user, if public.
+    pub email: Option<String>,
+    /// Username of the user.
+    pub username: Option<String>,
+    /// Full name of the user.
+    pub full_name: Option<String>,
+    /// Display name of the user, falling back to the username or an anonymous name.
+    pub display_name: String,
+    /// Website of the user.
+    pub website: Option<String>,
+    /// Website of the user without the protocol.
+    pub human_readable_website: Option<String>,
+    /// Whether the user is open to job offers.
+    pub is_hireable: bool,
+    /// Location of the user, if public.
+    pub city: Option<LeaderCity>,
+    /// Whether the email address of the user is public.
+    pub is_email_public: bool,
+    /// Whether the photo of the user is public.
+    pub photo_public: bool,
+    /// URL of the user's avatar.
+    pub photo: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderCity {
+    /// Two letter code of the country, for ex: `US`.
+    pub country_code: Option<String>,
+    /// Name of the city.
+    pub name: Option<String>,
+    /// Name of the state, if any.
+    pub state: Option<String>,
+    /// Name of the city including its state and country.
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeadersRange {
+    /// Start of the range as an ISO 8601 date.
+    pub start_date: String,
+    /// Start of the range in human readable format.
+    pub start_text: String,
+    /// End of the range as an ISO 8601 date.
+    pub end_date: String,
+    /// End of the range in human readable format.
+    pub end_text: String,
+    /// Name of the range, for ex: `last_7_days`.
+    pub name: String,
+    /// The range in human readable format.
+    pub text: String,
+}
+
+#[cfg(feature = "time")]
+impl LeadersRange {
+    /// Parses [`start_date`](Self::start_date) as a date.
+    pub fn parsed_start_date(&self) -> Result<time::Date, time::error::Parse> {
+        crate::date::parse_date(&self.start_date)
+    }
+
+    /// Parses [`end_date`](Self::end_date) as a date.
+    pub fn parsed_end_date(&self) -> Result<time::Date, time::error::Parse> {
+        crate::date::parse_date(&self.end_date)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrivateLeaderboards {
+    /// The private leaderboards the user belongs to.
+    pub data: Vec<PrivateLeaderboard>,
+    /// Total number of private leaderboards.
+    pub total: u32,
+    /// Number of pages of private leaderboards.
+    pub total_pages: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrivateLeaderboard {
+    /// Unique id of this leaderboard.
+    pub id: String,
+    /// Name of this leaderboard.
+    pub name: String,
+    /// Number of members of this leaderboard.
+    pub members_count: u32,
+    /// Number of members with a configured timezone.
+    pub members_with_timezones_count: Option<u32>,
+    /// Range of this leaderboard, for ex: `last_7_days`.
+    pub time_range: String,
+    /// Whether more members can join this leaderboard.
+    pub has_available_seat: Option<bool>,
+    /// Time when this leaderboard was created in ISO 8601 format.
+    pub created_at: String,
+    /// Time when this leaderboard was last modified in ISO 8601 format.
+    pub modified_at: Option<String>,
+}
+
+#[cfg(feature = "time")]
+impl PrivateLeaderboard {
+    /// Parses [`created_at`](Self::created_at) as a datetime.
+    pub fn parsed_created_at(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.created_at)
+    }
+}
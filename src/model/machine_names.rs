@@ -0,0 +1,34 @@
+//! Contains the models for [`WakaTimeClient::machine_names`](crate::WakaTimeClient::machine_names).
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MachineName {
+    /// Unique id of this machine, as referenced by the `machine_name_id` of other models.
+    pub id: String,
+    /// Name of this machine, as shown on the dashboard.
+    pub name: String,
+    /// The hostname sent by the plugins running on this machine.
+    pub value: String,
+    /// IP address this machine last sent heartbeats from.
+    pub ip: Option<String>,
+    /// Time when a heartbeat was last sent from this machine in ISO 8601 format.
+    pub last_seen_at: Option<String>,
+    /// Time when this machine was first seen in ISO 8601 format.
+    pub created_at: String,
+}
+
+#[cfg(feature = "time")]
+impl MachineName {
+    /// Parses [`last_seen_at`](Self::last_seen_at) as a datetime, if any.
+    pub fn parsed_last_seen_at(&self) -> Option<Result<time::OffsetDateTime, time::error::Parse>> {
+        self.last_seen_at
+            .as_deref()
+            .map(crate::date::parse_datetime)
+    }
+
+    /// Parses [`created_at`](Self::created_at) as a datetime.
+    pub fn parsed_created_at(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.created_at)
+    }
+}
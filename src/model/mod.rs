@@ -2,8 +2,27 @@
 
 pub mod all_times_since_today;
 pub mod commit;
+pub mod durations;
+pub mod goals;
+pub mod heartbeats;
+pub mod leaders;
+pub mod machine_names;
+pub mod orgs;
+pub mod page;
+pub mod projects;
+pub mod stats;
+pub mod status_bar;
 pub mod summaries;
+pub mod user;
+pub mod user_agents;
 
 pub use all_times_since_today::AllTimeSinceToday;
 pub use commit::Commits;
+pub use durations::Durations;
+pub use goals::Goals;
+pub use heartbeats::Heartbeats;
+pub use page::{Page, PageInfo, Paginated};
+pub use stats::Stats;
+pub use status_bar::StatusBar;
 pub use summaries::Summaries;
+pub use user::User;
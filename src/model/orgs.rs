@@ -0,0 +1,119 @@
+//! Contains the models for [`WakaTimeClient::orgs`](crate::WakaTimeClient::orgs),
+//! [`WakaTimeClient::org_dashboards`](crate::WakaTimeClient::org_dashboards)
+//! and [`WakaTimeClient::org_dashboard_members`](crate::WakaTimeClient::org_dashboard_members).
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Orgs {
+    /// The organizations the user belongs to.
+    pub data: Vec<Org>,
+    /// Total number of organizations.
+    pub total: u32,
+    /// Number of pages of organizations.
+    pub total_pages: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Org {
+    /// Unique id of this organization.
+    pub id: String,
+    /// Name of this organization.
+    pub name: String,
+    /// Keystroke timeout of the members in minutes.
+    pub timeout: u32,
+    /// Whether only file writes are counted as coding activity.
+    pub writes_only: bool,
+    /// Timezone used for the dashboards of this organization, for ex: `America/Los_Angeles`.
+    pub timezone: String,
+    /// Privacy of new projects, for ex: `visible`.
+    pub default_project_privacy: Option<String>,
+    /// Number of people invited to this organization.
+    pub invited_people_count: Option<u32>,
+    /// Number of people invited to this organization in human readable format.
+    pub invited_people_count_human_readable: Option<String>,
+    /// Whether the durations of the members are visible.
+    pub is_duration_visible: Option<bool>,
+    /// Number of members of this organization.
+    pub people_count: Option<u32>,
+    /// Number of members of this organization in human readable format.
+    pub people_count_human_readable: Option<String>,
+    /// Time when this organization was created in ISO 8601 format.
+    pub created_at: String,
+    /// Time when this organization was last modified in ISO 8601 format.
+    pub modified_at: Option<String>,
+}
+
+#[cfg(feature = "time")]
+impl Org {
+    /// Parses [`created_at`](Self::created_at) as a datetime.
+    pub fn parsed_created_at(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.created_at)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgDashboards {
+    /// The dashboards of the organization visible to the user.
+    pub data: Vec<OrgDashboard>,
+    /// Total number of dashboards.
+    pub total: u32,
+    /// Number of pages of dashboards.
+    pub total_pages: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgDashboard {
+    /// Unique id of this dashboard.
+    pub id: String,
+    /// Name of this dashboard.
+    pub full_name: String,
+    /// Timezone used for this dashboard, if it differs from the organization's.
+    pub timezone: Option<String>,
+    /// Number of members of this dashboard.
+    pub members_count: u32,
+    /// Number of members of this dashboard in human readable format.
+    pub members_count_human_readable: Option<String>,
+    /// Whether the user is a member of this dashboard.
+    pub is_current_user_member: bool,
+    /// Whether the user may list the members of this dashboard.
+    pub can_current_user_list_members: bool,
+    /// Whether the user may add members to this dashboard.
+    pub can_current_user_add_members: bool,
+    /// Whether the user may remove members from this dashboard.
+    pub can_current_user_remove_members: bool,
+    /// Whether the user may edit this dashboard.
+    pub can_current_user_edit: bool,
+    /// Whether the user may delete this dashboard.
+    pub can_current_user_delete: bool,
+    /// Time when this dashboard was created in ISO 8601 format.
+    pub created_at: String,
+    /// Time when this dashboard was last modified in ISO 8601 format.
+    pub modified_at: Option<String>,
+}
+
+#[cfg(feature = "time")]
+impl OrgDashboard {
+    /// Parses [`created_at`](Self::created_at) as a datetime.
+    pub fn parsed_created_at(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.created_at)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgDashboardMember {
+    /// Unique id of the user.
+    pub id: String,
+    /// Email address of the user, if visible.
+    pub email: Option<String>,
+    /// Full name of the user.
+    pub full_name: Option<String>,
+    /// Username of the user.
+    pub username: Option<String>,
+    /// URL of the user's avatar.
+    pub photo: Option<String>,
+    /// Whether the member can view this dashboard without sharing their own coding activity.
+    pub is_view_only: Option<bool>,
+    /// Whether the member only shares their coding activity with this dashboard.
+    pub is_only_viewing_dashboard: Option<bool>,
+}
@@ -0,0 +1,66 @@
+//! Contains the paging metadata shared by all paginated endpoints,
+//! see [`WakaTimeClient::paginate`](crate::WakaTimeClient::paginate).
+
+use serde::{Deserialize, Serialize};
+
+/// The paging metadata of a single page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageInfo {
+    /// Current page number.
+    pub page: u32,
+    /// Number of pages.
+    pub total_pages: u32,
+    /// Total number of items, if reported by the endpoint.
+    pub total: Option<u32>,
+    /// Next page number, if there is one.
+    pub next_page: Option<u32>,
+    /// Previous page number, if there is one.
+    pub prev_page: Option<u32>,
+}
+
+impl PageInfo {
+    /// Returns the number of the page following this one, if any.
+    ///
+    /// Endpoints that do not report `next_page` are paged using `total_pages`. A `next_page`
+    /// not after this page is ignored, so that walking the pages always ends.
+    pub fn next(&self) -> Option<u32> {
+        match self.next_page {
+            Some(next) if next > self.page => Some(next),
+            Some(_) => None,
+            None if self.page < self.total_pages => Some(self.page + 1),
+            None => None,
+        }
+    }
+}
+
+/// A single page of an endpoint that returns its items in the `data` field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub data: Vec<T>,
+    #[serde(flatten)]
+    pub page: PageInfo,
+}
+
+/// A single page of a paginated endpoint.
+pub trait Paginated {
+    /// The type of the items on a page.
+    type Item;
+
+    /// Gets the paging metadata of this page.
+    fn page_info(&self) -> &PageInfo;
+
+    /// Consumes the page, returning its items.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+impl<T> Paginated for Page<T> {
+    type Item = T;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.data
+    }
+}
@@ -0,0 +1,59 @@
+//! Contains the models for [`WakaTimeClient::projects`](crate::WakaTimeClient::projects).
+
+use crate::model::commit::Repository;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    /// Unique id of this project.
+    pub id: String,
+    /// Project name; pass this to [`WakaTimeClient::commits`](crate::WakaTimeClient::commits)
+    /// as is, since the client encodes it for the URL.
+    pub name: String,
+    /// Project name, url entity encoded; only use this when building URLs by hand.
+    pub urlencoded_name: Option<String>,
+    /// Associated repository, if this project is connected to one.
+    pub repository: Option<Repository>,
+    /// Associated project badge, if any.
+    pub badge: Option<ProjectBadge>,
+    /// Custom project color as hex string, or `null` if using the default color.
+    pub color: Option<String>,
+    /// Whether this project has a shareable url defined.
+    pub has_public_url: bool,
+    /// Time of most recent heartbeat in human readable format relative to the current day.
+    pub human_readable_last_heartbeat_at: Option<String>,
+    /// Time of most recent heartbeat in ISO 8601 format.
+    pub last_heartbeat_at: Option<String>,
+    /// Url of this project's dashboard.
+    pub url: Option<String>,
+    /// Time when project was created in ISO 8601 format.
+    pub created_at: String,
+}
+
+#[cfg(feature = "time")]
+impl Project {
+    /// Parses [`created_at`](Self::created_at) as a datetime.
+    pub fn parsed_created_at(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.created_at)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectBadge {
+    /// Unique id of this badge.
+    pub id: String,
+    /// Badge color as hex string.
+    pub color: Option<String>,
+    /// Text shown on the left side of the badge.
+    pub left_text: Option<String>,
+    /// Link the badge points to.
+    pub link: Option<String>,
+    /// Unique id of the project this badge belongs to.
+    pub project_id: Option<String>,
+    /// Title of the badge.
+    pub title: Option<String>,
+    /// Url of the badge image.
+    pub url: Option<String>,
+    /// Time when badge was created in ISO 8601 format.
+    pub created_at: Option<String>,
+}
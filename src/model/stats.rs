@@ -0,0 +1,150 @@
+//! Contains the models for [`WakaTimeClient::stats`](crate::WakaTimeClient::stats).
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Stats {
+    /// Total coding activity as seconds for the given range of time.
+    pub total_seconds: Option<f64>,
+    /// Total coding activity as seconds for the given range of time, including Other language.
+    pub total_seconds_including_other_language: Option<f64>,
+    /// Total coding activity as human readable string.
+    pub human_readable_total: Option<String>,
+    /// Total coding activity as human readable string, including Other language.
+    pub human_readable_total_including_other_language: Option<String>,
+    /// Average coding activity per day as seconds for the given range of time, excluding Other language.
+    pub daily_average: Option<f64>,
+    /// Average coding activity per day as seconds for the given range of time.
+    pub daily_average_including_other_language: Option<f64>,
+    /// Daily average, excluding Other language, as human readable string.
+    pub human_readable_daily_average: Option<String>,
+    /// Daily average as human readable string.
+    pub human_readable_daily_average_including_other_language: Option<String>,
+    pub categories: Option<Vec<StatsEntry>>,
+    pub projects: Option<Vec<StatsEntry>>,
+    pub languages: Option<Vec<StatsEntry>>,
+    pub editors: Option<Vec<StatsEntry>>,
+    pub operating_systems: Option<Vec<StatsEntry>>,
+    pub dependencies: Option<Vec<StatsEntry>>,
+    pub machines: Option<Vec<StatsMachine>>,
+    pub best_day: Option<StatsBestDay>,
+    /// Time range of these stats, for ex: `last_7_days`.
+    pub range: String,
+    /// Time range as human readable string.
+    pub human_readable_range: Option<String>,
+    /// Number of days in this range with no coding time logged.
+    pub holidays: Option<u32>,
+    /// Number of days in this range.
+    pub days_including_holidays: Option<u32>,
+    /// Number of days in this range excluding days with no activity.
+    pub days_minus_holidays: Option<u32>,
+    /// Status of these stats in the cache.
+    pub status: String,
+    /// A number between 0 and 100 where 100 means the stats are up to date including Today’s time.
+    pub percent_calculated: Option<u8>,
+    /// `true` if these stats are being updated in the background.
+    pub is_already_updating: bool,
+    /// `true` if this user's coding activity is publicly visible.
+    pub is_coding_activity_visible: bool,
+    /// `true` if this user's language stats are publicly visible.
+    pub is_other_usage_visible: bool,
+    /// `true` if these stats got stuck while processing and will be recalculated in the background.
+    pub is_stuck: bool,
+    /// `true` if these stats include the current day; normally `false` except range `all_time`.
+    pub is_including_today: bool,
+    /// `true` if these stats are up to date; when `false`, a 202 response code is returned and stats will be refreshed soon.
+    pub is_up_to_date: bool,
+    /// Start of this time range as ISO 8601 UTC datetime.
+    pub start: String,
+    /// End of this time range as ISO 8601 UTC datetime.
+    pub end: String,
+    /// Timezone used in Olson Country/Region format.
+    pub timezone: String,
+    /// Value of the user's keystroke timeout setting in minutes.
+    pub timeout: u32,
+    /// Status of the user's writes_only setting.
+    pub writes_only: bool,
+    /// Unique id of this user.
+    pub user_id: String,
+    /// Public username for this user.
+    pub username: Option<String>,
+    /// Time when these stats were created in ISO 8601 format.
+    pub created_at: String,
+    /// Time when these stats were last updated in ISO 8601 format.
+    pub modified_at: Option<String>,
+}
+
+#[cfg(feature = "time")]
+impl Stats {
+    /// Parses [`start`](Self::start) as a datetime.
+    pub fn parsed_start(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.start)
+    }
+
+    /// Parses [`end`](Self::end) as a datetime.
+    pub fn parsed_end(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.end)
+    }
+
+    /// Parses [`created_at`](Self::created_at) as a datetime.
+    pub fn parsed_created_at(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.created_at)
+    }
+}
+
+/// A category, project, language, editor, operating system or dependency of [`Stats`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsEntry {
+    /// Name of the entry, for ex: the language or project name.
+    pub name: String,
+    /// Total coding activity as seconds.
+    pub total_seconds: f64,
+    /// Percent of time spent in this entry.
+    pub percent: f32,
+    /// Total coding activity for this entry in digital clock format.
+    pub digital: String,
+    /// Total coding activity in human readable format.
+    pub text: String,
+    /// Hours portion of coding activity for this entry.
+    pub hours: u32,
+    /// Minutes portion of coding activity for this entry.
+    pub minutes: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsMachine {
+    /// Machine hostname and ip address.
+    pub name: String,
+    /// Unique id of this machine, see [`WakaTimeClient::machine_names`](crate::WakaTimeClient::machine_names).
+    pub machine_name_id: String,
+    /// Total coding activity spent on this machine as seconds.
+    pub total_seconds: f64,
+    /// Percent of time spent on this machine.
+    pub percent: f32,
+    /// Total coding activity for this machine in digital clock format.
+    pub digital: String,
+    /// Total coding activity in human readable format.
+    pub text: String,
+    /// Hours portion of coding activity for this machine.
+    pub hours: u32,
+    /// Minutes portion of coding activity for this machine.
+    pub minutes: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsBestDay {
+    /// Day with most coding time logged as Date string in YEAR-MONTH-DAY format.
+    pub date: String,
+    /// Total coding activity for this day in human readable format.
+    pub text: String,
+    /// Number of seconds of coding activity, including Other language, for this day.
+    pub total_seconds: f64,
+}
+
+#[cfg(feature = "time")]
+impl StatsBestDay {
+    /// Parses [`date`](Self::date) as a calendar date.
+    pub fn parsed_date(&self) -> Result<time::Date, time::error::Parse> {
+        crate::date::parse_date(&self.date)
+    }
+}
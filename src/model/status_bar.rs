@@ -0,0 +1,29 @@
+//! Contains the models for [`WakaTimeClient::status_bar_today`](crate::WakaTimeClient::status_bar_today).
+
+use crate::model::summaries::{
+    SummaryCategory, SummaryDependency, SummaryEditor, SummaryGrandTotal, SummaryLanguage,
+    SummaryMachine, SummaryOperatingSystem, SummaryProject, SummaryRange,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusBar {
+    /// Time when this response was cached in ISO 8601 format.
+    pub cached_at: Option<String>,
+    pub data: StatusBarToday,
+    /// Whether the user has access to team features.
+    pub has_team_features: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusBarToday {
+    pub grand_total: SummaryGrandTotal,
+    pub categories: Vec<SummaryCategory>,
+    pub projects: Vec<SummaryProject>,
+    pub languages: Vec<SummaryLanguage>,
+    pub editors: Vec<SummaryEditor>,
+    pub operating_systems: Vec<SummaryOperatingSystem>,
+    pub dependencies: Vec<SummaryDependency>,
+    pub machines: Vec<SummaryMachine>,
+    pub range: SummaryRange,
+}
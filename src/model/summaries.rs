@@ -1,4 +1,5 @@
-//! Contains the models for [`WakaTimeClient::summaries`](crate::WakaTimeClient::summaries).
+//! Contains the models for [`WakaTimeClient::summaries`](crate::WakaTimeClient::summaries)
+//! and [`WakaTimeClient::org_dashboard_member_summaries`](crate::WakaTimeClient::org_dashboard_member_summaries).
 
 use serde::{Deserialize, Serialize};
 
@@ -13,6 +14,19 @@ pub struct Summaries {
     pub end: String,
 }
 
+#[cfg(feature = "time")]
+impl Summaries {
+    /// Parses [`start`](Self::start) as a datetime.
+    pub fn parsed_start(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.start)
+    }
+
+    /// Parses [`end`](Self::end) as a datetime.
+    pub fn parsed_end(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.end)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Summary {
     pub grand_total: SummaryGrandTotal,
@@ -150,7 +164,7 @@ pub struct SummaryDependency {
 pub struct SummaryMachine {
     /// Machine hostname and ip address.
     pub name: String,
-    /// Unique id of this machine.
+    /// Unique id of this machine, see [`WakaTimeClient::machine_names`](crate::WakaTimeClient::machine_names).
     pub machine_name_id: String,
     /// Total coding activity spent on this machine as seconds.
     pub total_seconds: f32,
@@ -236,6 +250,24 @@ pub struct SummaryRange {
     pub timezone: String,
 }
 
+#[cfg(feature = "time")]
+impl SummaryRange {
+    /// Parses [`date`](Self::date) as a calendar date.
+    pub fn parsed_date(&self) -> Result<time::Date, time::error::Parse> {
+        crate::date::parse_date(&self.date)
+    }
+
+    /// Parses [`start`](Self::start) as a datetime.
+    pub fn parsed_start(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.start)
+    }
+
+    /// Parses [`end`](Self::end) as a datetime.
+    pub fn parsed_end(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.end)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CumulativeTotalSummary {
     /// Cumulative number of seconds over the date range of summaries.
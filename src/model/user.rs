@@ -0,0 +1,76 @@
+//! Contains the models for [`WakaTimeClient::user`](crate::WakaTimeClient::user).
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    /// Unique id of the user.
+    pub id: String,
+    /// Username of the user, if set.
+    pub username: Option<String>,
+    /// Display name of the user, falling back to the username or an anonymous name.
+    pub display_name: String,
+    /// Full name of the user.
+    pub full_name: Option<String>,
+    /// Email address of the user; requires the `email` scope when using OAuth.
+    pub email: Option<String>,
+    /// Whether the email address of the user is public.
+    pub is_email_public: Option<bool>,
+    /// Whether the email address of the user was confirmed.
+    pub is_email_confirmed: Option<bool>,
+    /// Timezone used for the coding activity of the user, for ex: `America/Los_Angeles`.
+    pub timezone: String,
+    /// Keystroke timeout in minutes; time between heartbeats longer than this is not counted.
+    pub timeout: u32,
+    /// Whether only file writes are counted as coding activity.
+    pub writes_only: bool,
+    /// Subscription plan of the user, for ex: `free`.
+    pub plan: Option<String>,
+    /// Whether the user has premium features.
+    pub has_premium_features: Option<bool>,
+    /// URL of the user's avatar.
+    pub photo: Option<String>,
+    /// Whether the photo of the user is public.
+    pub photo_public: Option<bool>,
+    /// Website of the user.
+    pub website: Option<String>,
+    /// Location of the user.
+    pub location: Option<String>,
+    /// Whether the user is open to job offers.
+    pub is_hireable: Option<bool>,
+    /// Whether the coding time of the user is public.
+    pub logged_time_public: Option<bool>,
+    /// Whether the languages used by the user are public.
+    pub languages_used_public: Option<bool>,
+    /// User agent of the plugin which sent the most recent heartbeat.
+    pub last_plugin: Option<String>,
+    /// Name of the editor of the plugin which sent the most recent heartbeat, for ex: `vscode`.
+    pub last_plugin_name: Option<String>,
+    /// Project of the most recent heartbeat.
+    pub last_project: Option<String>,
+    /// Branch of the most recent heartbeat.
+    pub last_branch: Option<String>,
+    /// Time of the most recent heartbeat in ISO 8601 format.
+    pub last_heartbeat_at: Option<String>,
+    /// Time when the user was created in ISO 8601 format.
+    pub created_at: String,
+    /// Time when the user was last modified in ISO 8601 format.
+    pub modified_at: Option<String>,
+}
+
+#[cfg(feature = "time")]
+impl User {
+    /// Parses [`created_at`](Self::created_at) as a datetime.
+    pub fn parsed_created_at(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.created_at)
+    }
+
+    /// Parses [`last_heartbeat_at`](Self::last_heartbeat_at) as a datetime, if any.
+    pub fn parsed_last_heartbeat_at(
+        &self,
+    ) -> Option<Result<time::OffsetDateTime, time::error::Parse>> {
+        self.last_heartbeat_at
+            .as_deref()
+            .map(crate::date::parse_datetime)
+    }
+}
@@ -0,0 +1,40 @@
+//! Contains the models for [`WakaTimeClient::user_agents`](crate::WakaTimeClient::user_agents).
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserAgent {
+    /// Unique id of this user agent.
+    pub id: String,
+    /// The full user agent string sent by the plugin.
+    pub value: String,
+    /// Editor of the plugin, for ex: `vscode`.
+    pub editor: Option<String>,
+    /// Version of the plugin, for ex: `14.2.1`.
+    pub version: Option<String>,
+    /// Operating system the plugin ran on, for ex: `linux`.
+    pub os: Option<String>,
+    /// Whether the plugin is a browser extension.
+    pub is_browser_extension: bool,
+    /// Whether the plugin is a desktop app.
+    pub is_desktop_app: bool,
+    /// Time when a heartbeat was last sent by this user agent in ISO 8601 format.
+    pub last_seen_at: Option<String>,
+    /// Time when this user agent was first seen in ISO 8601 format.
+    pub created_at: String,
+}
+
+#[cfg(feature = "time")]
+impl UserAgent {
+    /// Parses [`last_seen_at`](Self::last_seen_at) as a datetime, if any.
+    pub fn parsed_last_seen_at(&self) -> Option<Result<time::OffsetDateTime, time::error::Parse>> {
+        self.last_seen_at
+            .as_deref()
+            .map(crate::date::parse_datetime)
+    }
+
+    /// Parses [`created_at`](Self::created_at) as a datetime.
+    pub fn parsed_created_at(&self) -> Result<time::OffsetDateTime, time::error::Parse> {
+        crate::date::parse_datetime(&self.created_at)
+    }
+}
@@ -0,0 +1,362 @@
+//! The OAuth 2.0 flow for acting on behalf of other WakaTime users.
+//!
+//! Register an app at [wakatime.com/apps](https://wakatime.com/apps), send the user to the
+//! [`OAuthClient::authorize_url`] and exchange the code WakaTime redirects back with for an
+//! [`AccessToken`]. The token is then used to build a client using
+//! [`WakaTimeClientBuilder::new_with_access_token`](crate::WakaTimeClientBuilder::new_with_access_token),
+//! which fails fast with [`ApiError::MissingScope`](crate::ApiError::MissingScope) when
+//! calling an endpoint the token does not grant access to.
+//!
+//! ```no_run
+//! use waka::oauth::{OAuthClient, Scope};
+//! use waka::WakaTimeClientBuilder;
+//!
+//! # async fn test() -> Result<(), Box<dyn std::error::Error>> {
+//! let oauth = OAuthClient::new("client-id", "client-secret", "https://example.com/callback")?;
+//! let url = oauth.authorize_url(&[Scope::ReadStats, Scope::ReadLoggedTime], Some("csrf-token"));
+//!
+//! // Redirect the user to `url`, then take the `code` from the callback.
+//! let token = oauth.exchange_code("code-from-callback").await?;
+//! let client = WakaTimeClientBuilder::new_with_access_token(&token).build()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Documentation
+//! * [Authentication](https://wakatime.com/developers#authentication)
+
+use crate::transport::{self, Method, Request, Transport, TransportError};
+use query_string_builder::QueryString;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The base URL of the OAuth endpoints.
+const DEFAULT_BASE_URL: &str = "https://wakatime.com/oauth/";
+
+/// A permission requested from the user during the OAuth flow.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// Access to the stats, including the all-time totals.
+    ReadStats,
+    /// Access to the coding activity, such as summaries, durations, heartbeats and projects.
+    ReadLoggedTime,
+    /// Permission to send heartbeats.
+    WriteLoggedTime,
+    /// Access to the goals.
+    ReadGoals,
+    /// Access to the organizations and their dashboards.
+    ReadOrgs,
+    /// Permission to modify the organizations.
+    WriteOrgs,
+    /// Access to the private leaderboards.
+    ReadPrivateLeaderboards,
+    /// Permission to modify the private leaderboards.
+    WritePrivateLeaderboards,
+    /// Access to the user's email address.
+    Email,
+}
+
+impl Scope {
+    /// Returns the name of the scope as used by the API, e.g. `read_stats`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ReadStats => "read_stats",
+            Self::ReadLoggedTime => "read_logged_time",
+            Self::WriteLoggedTime => "write_logged_time",
+            Self::ReadGoals => "read_goals",
+            Self::ReadOrgs => "read_orgs",
+            Self::WriteOrgs => "write_orgs",
+            Self::ReadPrivateLeaderboards => "read_private_leaderboards",
+            Self::WritePrivateLeaderboards => "write_private_leaderboards",
+            Self::Email => "email",
+        }
+    }
+
+    /// Gets the scope by its name as used by the API, e.g. `read_stats`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "read_stats" => Some(Self::ReadStats),
+            "read_logged_time" => Some(Self::ReadLoggedTime),
+            "write_logged_time" => Some(Self::WriteLoggedTime),
+            "read_goals" => Some(Self::ReadGoals),
+            "read_orgs" => Some(Self::ReadOrgs),
+            "write_orgs" => Some(Self::WriteOrgs),
+            "read_private_leaderboards" => Some(Self::ReadPrivateLeaderboards),
+            "write_private_leaderboards" => Some(Self::WritePrivateLeaderboards),
+            "email" => Some(Self::Email),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Scope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The token granted by the user, as returned by [`OAuthClient::exchange_code`]
+/// and [`OAuthClient::refresh`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessToken {
+    /// The token to authorize requests with.
+    pub access_token: String,
+    /// The token to obtain a new access token with once this one expires.
+    pub refresh_token: Option<String>,
+    /// The type of the token, i.e. `bearer`.
+    pub token_type: String,
+    /// The unique id of the user who granted the token.
+    pub uid: String,
+    /// The granted scopes, separated by commas.
+    pub scope: String,
+    /// The number of seconds until the token expires.
+    pub expires_in: Option<u64>,
+    /// The time at which the token expires in ISO 8601 format.
+    pub expires_at: Option<String>,
+}
+
+impl AccessToken {
+    /// Gets the granted scopes, ignoring those not known to this crate.
+    pub fn scopes(&self) -> Vec<Scope> {
+        self.scope_names().filter_map(Scope::from_name).collect()
+    }
+
+    /// Determines whether any granted scope is not known to this crate, in which case
+    /// [`scopes`](Self::scopes) does not tell which endpoints the token may access.
+    pub fn has_unknown_scopes(&self) -> bool {
+        self.scope_names()
+            .any(|name| Scope::from_name(name).is_none())
+    }
+
+    fn scope_names(&self) -> impl Iterator<Item = &str> {
+        self.scope.split([',', ' ']).filter(|name| !name.is_empty())
+    }
+}
+
+/// The error response of the OAuth endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct OAuthErrorResponse {
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+/// A client for the OAuth endpoints, identified by the credentials of a registered app.
+pub struct OAuthClient {
+    /// The transport sending the requests.
+    transport: Box<dyn Transport>,
+    /// The id of the app.
+    client_id: String,
+    /// The secret of the app.
+    client_secret: String,
+    /// The URL WakaTime redirects the user to after authorizing the app.
+    redirect_uri: String,
+    /// The base URL of the OAuth endpoints, always ending in a slash.
+    base_url: String,
+}
+
+impl OAuthClient {
+    /// Creates a client for the app with the given credentials, as shown at
+    /// [wakatime.com/apps](https://wakatime.com/apps).
+    pub fn new<S: AsRef<str>>(
+        client_id: S,
+        client_secret: S,
+        redirect_uri: S,
+    ) -> Result<Self, TransportError> {
+        Ok(Self {
+            transport: Box::new(transport::ReqwestTransport::new()?),
+            client_id: client_id.as_ref().to_string(),
+            client_secret: client_secret.as_ref().to_string(),
+            redirect_uri: redirect_uri.as_ref().to_string(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+        })
+    }
+
+    /// Specifies the transport sending the requests.
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Box::new(transport);
+        self
+    }
+
+    /// Specifies the base URL of the OAuth endpoints. If unspecified,
+    /// `https://wakatime.com/oauth/` is used.
+    pub fn with_base_url<S: AsRef<str>>(mut self, base_url: S) -> Self {
+        self.base_url = base_url.as_ref().to_string();
+        if !self.base_url.ends_with('/') {
+            self.base_url.push('/');
+        }
+        self
+    }
+
+    /// Builds the URL to send the user to for authorizing the app with the given scopes.
+    ///
+    /// The `state` is passed back unchanged to the redirect URI and should be used to
+    /// protect against cross-site request forgery.
+    pub fn authorize_url(&self, scopes: &[Scope], state: Option<&str>) -> String {
+        let scope = scopes
+            .iter()
+            .map(Scope::as_str)
+            .collect::<Vec<_>>()
+            .join(",");
+        let qs = QueryString::new()
+            .with_value("client_id", &self.client_id)
+            .with_value("response_type", "code")
+            .with_value("redirect_uri", &self.redirect_uri)
+            .with_value("scope", scope)
+            .with_opt_value("state", state);
+        format!("{base_url}authorize{qs}", base_url = self.base_url)
+    }
+
+    /// Exchanges the code passed to the redirect URI for an access token.
+    pub async fn exchange_code(&self, code: &str) -> Result<AccessToken, OAuthError> {
+        let form = self
+            .credentials()
+            .with_value("grant_type", "authorization_code")
+            .with_value("redirect_uri", &self.redirect_uri)
+            .with_value("code", code);
+        let body = self.post("token", form).await?;
+        serde_json::from_slice(&body).map_err(OAuthError::InvalidFormat)
+    }
+
+    /// Obtains a new access token using the refresh token of an expiring one.
+    pub async fn refresh(&self, refresh_token: &str) -> Result<AccessToken, OAuthError> {
+        let form = self
+            .credentials()
+            .with_value("grant_type", "refresh_token")
+            .with_value("redirect_uri", &self.redirect_uri)
+            .with_value("refresh_token", refresh_token);
+        let body = self.post("token", form).await?;
+        serde_json::from_slice(&body).map_err(OAuthError::InvalidFormat)
+    }
+
+    /// Revokes an access or refresh token, e.g. when the user disconnects the app.
+    pub async fn revoke(&self, token: &str) -> Result<(), OAuthError> {
+        let form = self.credentials().with_value("token", token);
+        self.post("revoke", form).await.map(|_| ())
+    }
+
+    fn credentials(&self) -> QueryString {
+        QueryString::new()
+            .with_value("client_id", &self.client_id)
+            .with_value("client_secret", &self.client_secret)
+    }
+
+    /// Posts the form to an OAuth endpoint, returning the body of a successful response.
+    async fn post(&self, endpoint: &str, form: QueryString) -> Result<Vec<u8>, OAuthError> {
+        let form = form.to_string();
+        let request = Request {
+            method: Method::Post,
+            url: format!("{base_url}{endpoint}", base_url = self.base_url),
+            headers: vec![
+                ("accept".to_string(), "application/json".to_string()),
+                (
+                    "content-type".to_string(),
+                    "application/x-www-form-urlencoded".to_string(),
+                ),
+            ],
+            body: Some(form.trim_start_matches('?').as_bytes().to_vec()),
+        };
+
+        let response = self
+            .transport
+            .send(request)
+            .await
+            .map_err(OAuthError::RequestFailed)?;
+        if (200..300).contains(&response.status) {
+            return Ok(response.body);
+        }
+
+        let error = serde_json::from_slice::<OAuthErrorResponse>(&response.body).ok();
+        Err(OAuthError::Rejected {
+            status: response.status,
+            error: error.as_ref().and_then(|e| e.error.clone()),
+            description: error.and_then(|e| e.error_description),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum OAuthError {
+    /// The request could not be sent or its response could not be received.
+    RequestFailed(TransportError),
+    /// The API returned an unexpected format.
+    InvalidFormat(serde_json::Error),
+    /// The request was rejected, e.g. because the code or refresh token is invalid or expired.
+    Rejected {
+        /// The status code of the response.
+        status: u16,
+        /// The OAuth error code, e.g. `invalid_grant`.
+        error: Option<String>,
+        /// The human-readable description of the error.
+        description: Option<String>,
+    },
+}
+
+impl Display for OAuthError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OAuthError::RequestFailed(e) => write!(f, "The call failed: {e}"),
+            OAuthError::InvalidFormat(e) => {
+                write!(f, "The API returned an unexpected format: {e}")
+            }
+            OAuthError::Rejected {
+                status,
+                error,
+                description,
+            } => {
+                write!(f, "The request was rejected with {status}")?;
+                if let Some(error) = error {
+                    write!(f, ": {error}")?;
+                }
+                if let Some(description) = description {
+                    write!(f, " ({description})")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for OAuthError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            OAuthError::RequestFailed(e) => Some(e),
+            OAuthError::InvalidFormat(e) => Some(e),
+            OAuthError::Rejected { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(scope: &str) -> AccessToken {
+        AccessToken {
+            access_token: "waka_tok_test".to_string(),
+            refresh_token: None,
+            token_type: "bearer".to_string(),
+            uid: "user-id".to_string(),
+            scope: scope.to_string(),
+            expires_in: None,
+            expires_at: None,
+        }
+    }
+
+    #[test]
+    fn scopes_are_parsed() {
+        let token = token("read_stats,read_logged_time email");
+        assert_eq!(
+            token.scopes(),
+            [Scope::ReadStats, Scope::ReadLoggedTime, Scope::Email]
+        );
+        assert!(!token.has_unknown_scopes());
+    }
+
+    #[test]
+    fn unknown_scopes_are_detected() {
+        let token = token("read_stats,read_summaries.languages");
+        assert_eq!(token.scopes(), [Scope::ReadStats]);
+        assert!(token.has_unknown_scopes());
+    }
+}
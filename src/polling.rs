@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+/// Controls how data that is not up to date is polled, see
+/// [`WakaTimeClientBuilder::with_polling`](crate::WakaTimeClientBuilder::with_polling).
+#[derive(Debug, Clone)]
+pub struct PollingPolicy {
+    /// The total time to wait between polls after which polling is given up and
+    /// [`ApiError::NotUpToDate`](crate::ApiError::NotUpToDate) is returned.
+    pub timeout: Duration,
+    /// The time to wait before polling again for the first time.
+    pub initial_interval: Duration,
+    /// The longest time to wait between two polls; the interval doubles after each poll.
+    pub max_interval: Duration,
+}
+
+impl Default for PollingPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(60),
+            initial_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Tracks the polls of a single request.
+pub(crate) struct Poll {
+    /// The total time to wait between polls.
+    timeout: Duration,
+    /// The time waited between polls so far.
+    waited: Duration,
+    /// The time to wait before the next poll.
+    interval: Duration,
+    /// The longest time to wait between two polls.
+    max_interval: Duration,
+}
+
+impl Poll {
+    pub fn new(policy: &PollingPolicy) -> Self {
+        Self {
+            timeout: policy.timeout,
+            waited: Duration::ZERO,
+            interval: policy.initial_interval,
+            max_interval: policy.max_interval,
+        }
+    }
+
+    /// Returns the time to wait before polling again, or `None` if the timeout would pass.
+    ///
+    /// Only the time waited is counted, so the timeout does not depend on how long the
+    /// transport actually sleeps.
+    pub fn next_interval(&mut self) -> Option<Duration> {
+        let interval = self.interval;
+        if self.waited + interval >= self.timeout {
+            return None;
+        }
+        self.waited += interval;
+        self.interval = (interval * 2).min(self.max_interval);
+        Some(interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intervals_double_until_the_timeout() {
+        let mut poll = Poll::new(&PollingPolicy {
+            timeout: Duration::from_secs(20),
+            initial_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(5),
+        });
+        let intervals = std::iter::from_fn(|| poll.next_interval())
+            .map(|interval| interval.as_secs())
+            .collect::<Vec<_>>();
+        assert_eq!(intervals, [1, 2, 4, 5, 5]);
+    }
+}
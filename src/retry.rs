@@ -0,0 +1,228 @@
+use crate::transport::Response;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Controls how failed requests are retried, see
+/// [`WakaTimeClientBuilder::with_retry_policy`](crate::WakaTimeClientBuilder::with_retry_policy).
+///
+/// Requests are retried when the server responds with `429 Too Many Requests` or a `5xx`
+/// status code, or when the connection failed or timed out.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts per request, including the first one.
+    pub max_attempts: u32,
+    /// The time to wait before the first retry; the backoff doubles after each retry.
+    pub initial_backoff: Duration,
+    /// The longest time to wait between two attempts, unless the server asks for more.
+    pub max_backoff: Duration,
+    /// Whether to randomize the backoff to spread out retries of concurrent requests.
+    pub jitter: bool,
+    /// Whether to wait as long as the server asks for in its `Retry-After` header.
+    pub respect_retry_after: bool,
+    /// The longest time to wait for when the server asks for it in its `Retry-After` header.
+    /// If the server asks for more, the request is not retried and
+    /// [`ApiError::RateLimited`](crate::ApiError::RateLimited) is returned instead.
+    pub max_retry_after: Duration,
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Determines the time to wait before the next attempt, given the number of attempts so far
+    /// and the `Retry-After` time requested by the server, if any.
+    ///
+    /// Returns `None` if the server asks to wait longer than [`max_retry_after`](Self::max_retry_after).
+    pub(crate) fn backoff(&self, attempts: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        match retry_after.filter(|_| self.respect_retry_after) {
+            Some(retry_after) if retry_after > self.max_retry_after => None,
+            Some(retry_after) => Some(retry_after),
+            None => Some(self.exponential_backoff(attempts)),
+        }
+    }
+
+    /// Determines the time to wait before the next attempt, doubling with every attempt.
+    pub(crate) fn exponential_backoff(&self, attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(31);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            let half = backoff / 2;
+            half + half.mul_f64(fastrand::f64())
+        } else {
+            backoff
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            respect_retry_after: true,
+            max_retry_after: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Parses the `Retry-After` header of the response, given either in seconds or as an HTTP date.
+pub(crate) fn retry_after(response: &Response) -> Option<Duration> {
+    parse_retry_after(response.header("retry-after")?, SystemTime::now())
+}
+
+/// Parses a `Retry-After` value relative to the current time; dates in the past yield zero.
+fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = UNIX_EPOCH + Duration::from_secs(parse_http_date(value)?);
+    Some(date.duration_since(now).unwrap_or_default())
+}
+
+/// Parses an HTTP date such as `Sun, 06 Nov 1994 08:49:37 GMT` into seconds since the epoch.
+fn parse_http_date(value: &str) -> Option<u64> {
+    let (_weekday, rest) = value.split_once(", ")?;
+    let parts: Vec<&str> = rest.split(' ').collect();
+    let [day, month, year, time, "GMT"] = parts.as_slice() else {
+        return None;
+    };
+
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let month = MONTHS.iter().position(|m| m == month)? as u64 + 1;
+    let day: u64 = day.parse().ok()?;
+    let year: u64 = year.parse().ok()?;
+    let mut time = time.split(':').map(|v| v.parse::<u64>().ok());
+    let (hours, minutes, seconds) = (time.next()??, time.next()??, time.next()??);
+    if year < 1970 || !(1..=31).contains(&day) || hours > 23 || minutes > 59 || seconds > 60 {
+        return None;
+    }
+
+    // Days since the epoch of the proleptic Gregorian calendar, counting years from March.
+    let (year, month) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    Some(days * 86_400 + hours * 3_600 + minutes * 60 + seconds)
+}
+
+/// Indicates whether a response with the given status code may succeed when retried.
+pub(crate) fn is_retryable(status: u16) -> bool {
+    status == 429 || (500..600).contains(&status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            jitter: false,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn backoff_grows_exponentially() {
+        let policy = policy();
+        assert_eq!(policy.backoff(1, None), Some(Duration::from_millis(500)));
+        assert_eq!(policy.backoff(2, None), Some(Duration::from_secs(1)));
+        assert_eq!(policy.backoff(3, None), Some(Duration::from_secs(2)));
+        assert_eq!(policy.backoff(4, None), Some(Duration::from_secs(4)));
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let policy = policy();
+        assert_eq!(policy.backoff(7, None), Some(Duration::from_secs(30)));
+        assert_eq!(
+            policy.backoff(u32::MAX, None),
+            Some(Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn jitter_stays_within_half_and_full_backoff() {
+        let policy = RetryPolicy::default();
+        for _ in 0..100 {
+            let backoff = policy.backoff(3, None).unwrap();
+            assert!(backoff >= Duration::from_secs(1), "{backoff:?}");
+            assert!(backoff <= Duration::from_secs(2), "{backoff:?}");
+        }
+    }
+
+    #[test]
+    fn retry_after_overrides_backoff() {
+        let policy = policy();
+        let retry_after = Some(Duration::from_secs(45));
+        assert_eq!(policy.backoff(1, retry_after), retry_after);
+
+        let ignoring = RetryPolicy {
+            respect_retry_after: false,
+            ..policy.clone()
+        };
+        assert_eq!(
+            ignoring.backoff(1, retry_after),
+            Some(Duration::from_millis(500))
+        );
+    }
+
+    #[test]
+    fn retry_after_beyond_limit_gives_up() {
+        let policy = policy();
+        assert_eq!(policy.backoff(1, Some(Duration::from_secs(86_400))), None);
+        assert_eq!(
+            policy.backoff(1, Some(Duration::from_secs(60))),
+            Some(Duration::from_secs(60))
+        );
+    }
+
+    #[test]
+    fn parses_retry_after_seconds() {
+        let now = SystemTime::now();
+        assert_eq!(
+            parse_retry_after(" 120 ", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[test]
+    fn parses_retry_after_http_dates() {
+        let now = UNIX_EPOCH + Duration::from_secs(784_111_717);
+        assert_eq!(
+            parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT", now),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            parse_retry_after("Sun, 06 Nov 1994 08:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(
+            parse_http_date("Thu, 29 Feb 2024 12:00:00 GMT"),
+            Some(1_709_208_000)
+        );
+        assert_eq!(parse_http_date("Thu, 29 Feb 2024 12:00:00 PST"), None);
+        assert_eq!(parse_http_date("Thu, 29 Foo 2024 12:00:00 GMT"), None);
+    }
+}
@@ -0,0 +1,569 @@
+//! Test doubles for crates using this client.
+//!
+//! The [`FixtureTransport`] answers every request from memory instead of calling the
+//! WakaTime API. It serves canned JSON for every endpoint the client supports, can be told
+//! to respond with error statuses and records the requests it received. It does not wait
+//! between retries and polls, but records the times the client asked to wait.
+//!
+//! ```
+//! use waka::testing::FixtureTransport;
+//! use waka::transport::Method;
+//! use waka::{ApiError, StatsOptions, StatsRange, WakaTimeClientBuilder};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let transport = FixtureTransport::new();
+//! let client = WakaTimeClientBuilder::new_with_api_key("waka_test")
+//!     .with_transport(transport.clone())
+//!     .build()?;
+//!
+//! let options = StatsOptions {
+//!     timeout: Some(15),
+//!     ..Default::default()
+//! };
+//! let stats = client.stats(StatsRange::Last7Days, options).await?;
+//! assert_eq!(stats.range, "last_7_days");
+//!
+//! let request = transport.last_request().unwrap();
+//! assert_eq!(request.query(), Some("timeout=15"));
+//!
+//! transport.respond_with_status(Method::Get, "users/*/stats/*", 401);
+//! let result = client.stats(StatsRange::Last7Days, StatsOptions::default()).await;
+//! assert!(matches!(result, Err(ApiError::Unauthorized(_))));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::transport::{Method, Request, Response, SleepFuture, Transport, TransportFuture};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+/// The canned responses of all endpoints, by method and route.
+const FIXTURES: &[(Method, &str, u16, &str)] = &[
+    (
+        Method::Post,
+        "oauth/token",
+        200,
+        include_str!("fixtures/oauth_token.json"),
+    ),
+    (Method::Post, "oauth/revoke", 200, "{}"),
+    (
+        Method::Get,
+        "users/*",
+        200,
+        include_str!("fixtures/user.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/all_time_since_today",
+        200,
+        include_str!("fixtures/all_time_since_today.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/durations",
+        200,
+        include_str!("fixtures/durations.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/goals",
+        200,
+        include_str!("fixtures/goals.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/goals/*",
+        200,
+        include_str!("fixtures/goal.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/heartbeats",
+        200,
+        include_str!("fixtures/heartbeats.json"),
+    ),
+    (
+        Method::Post,
+        "users/*/heartbeats",
+        201,
+        include_str!("fixtures/send_heartbeat.json"),
+    ),
+    (
+        Method::Post,
+        "users/*/heartbeats.bulk",
+        201,
+        include_str!("fixtures/send_heartbeats_bulk.json"),
+    ),
+    (
+        Method::Get,
+        "leaders",
+        200,
+        include_str!("fixtures/leaders.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/leaderboards",
+        200,
+        include_str!("fixtures/private_leaderboards.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/leaderboards/*",
+        200,
+        include_str!("fixtures/private_leaderboard_leaders.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/machine_names",
+        200,
+        include_str!("fixtures/machine_names.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/orgs",
+        200,
+        include_str!("fixtures/orgs.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/orgs/*/dashboards",
+        200,
+        include_str!("fixtures/org_dashboards.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/orgs/*/dashboards/*/members",
+        200,
+        include_str!("fixtures/org_dashboard_members.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/orgs/*/dashboards/*/members/*/durations",
+        200,
+        include_str!("fixtures/durations.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/orgs/*/dashboards/*/members/*/summaries",
+        200,
+        include_str!("fixtures/summaries.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/projects",
+        200,
+        include_str!("fixtures/projects.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/projects/*/commits",
+        200,
+        include_str!("fixtures/commits.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/projects/*/commits/*",
+        200,
+        include_str!("fixtures/commit.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/stats/*",
+        200,
+        include_str!("fixtures/stats.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/status_bar/today",
+        200,
+        include_str!("fixtures/status_bar_today.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/summaries",
+        200,
+        include_str!("fixtures/summaries.json"),
+    ),
+    (
+        Method::Get,
+        "users/*/user_agents",
+        200,
+        include_str!("fixtures/user_agents.json"),
+    ),
+];
+
+/// A [`Transport`] answering requests with canned responses, for use in tests.
+///
+/// Clones share their state, so a clone can be handed to the
+/// [`WakaTimeClientBuilder`](crate::WakaTimeClientBuilder) while the original is used to
+/// change the responses and inspect the requests.
+///
+/// Routes are given relative to the base URL, with `*` matching any single path segment,
+/// e.g. `users/*/projects/*/commits`, and match the end of the request path. Responses set
+/// using [`respond_with`](Self::respond_with) take precedence over the fixtures, the most
+/// recent one first, so `respond_with_status(Method::Get, "*", 500)` fails every `GET`
+/// request. Among the fixtures, the longest matching route is used. Requests to unknown
+/// routes are answered with `404 Not Found`.
+#[derive(Debug, Clone, Default)]
+pub struct FixtureTransport {
+    state: Arc<Mutex<FixtureState>>,
+}
+
+#[derive(Debug, Default)]
+struct FixtureState {
+    /// Responses replacing the fixtures, most recent first.
+    routes: Vec<(Method, String, Response)>,
+    /// Responses to the next requests, regardless of their route.
+    queued: VecDeque<Response>,
+    /// The requests received so far.
+    requests: Vec<Request>,
+    /// The times the client asked to wait so far.
+    sleeps: Vec<Duration>,
+}
+
+impl FixtureTransport {
+    /// Creates a transport serving the canned responses of all endpoints.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Responds to all requests matching the method and route with the given status and body.
+    pub fn respond_with<S: Into<String>>(&self, method: Method, route: &str, status: u16, body: S) {
+        let response = json_response(status, body.into());
+        self.state()
+            .routes
+            .insert(0, (method, route.to_string(), response));
+    }
+
+    /// Responds to all requests matching the method and route with the given status and
+    /// a body as sent by the API for it, e.g. the calculation progress for `202 Accepted`.
+    pub fn respond_with_status(&self, method: Method, route: &str, status: u16) {
+        self.respond_with(method, route, status, status_body(status));
+    }
+
+    /// Responds to the next request with the given status, regardless of its route.
+    ///
+    /// Queued statuses are used in order, e.g. to answer a `429 Too Many Requests` followed
+    /// by the canned response when testing retries.
+    pub fn enqueue_status(&self, status: u16) {
+        self.enqueue(status, status_body(status));
+    }
+
+    /// Responds to the next request with the given status and body, regardless of its route.
+    ///
+    /// Queued responses are used in order, e.g. to answer the requests for the pages of a
+    /// paginated endpoint.
+    pub fn enqueue<S: Into<String>>(&self, status: u16, body: S) {
+        let response = json_response(status, body.into());
+        self.state().queued.push_back(response);
+    }
+
+    /// Gets the requests received so far, in order.
+    pub fn requests(&self) -> Vec<Request> {
+        self.state().requests.clone()
+    }
+
+    /// Gets the times the client asked to wait so far, in order, e.g. to check the backoff
+    /// between retries.
+    ///
+    /// The transport does not actually wait, so retries and polls complete immediately.
+    pub fn sleeps(&self) -> Vec<Duration> {
+        self.state().sleeps.clone()
+    }
+
+    /// Gets the most recent request received.
+    pub fn last_request(&self) -> Option<Request> {
+        self.state().requests.last().cloned()
+    }
+
+    /// Records the request and determines its response.
+    fn respond(&self, request: Request) -> Response {
+        let mut state = self.state();
+        let response = match state.queued.pop_front() {
+            Some(response) => response,
+            None => state.route(&request),
+        };
+        state.requests.push(request);
+        response
+    }
+
+    fn state(&self) -> MutexGuard<'_, FixtureState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl FixtureState {
+    /// Determines the response of the most recent custom route matching the request,
+    /// falling back to the most specific fixture.
+    fn route(&self, request: &Request) -> Response {
+        let path = request.path();
+        let custom = self
+            .routes
+            .iter()
+            .find(|(method, route, _)| *method == request.method && matches(route, path));
+        if let Some((_, _, response)) = custom {
+            return response.clone();
+        }
+
+        match fixture(request.method, path) {
+            Some((_, _, status, body)) => json_response(*status, body.to_string()),
+            None => json_response(404, status_body(404)),
+        }
+    }
+}
+
+impl Transport for FixtureTransport {
+    fn send(&self, request: Request) -> TransportFuture<'_> {
+        let response = self.respond(request);
+        Box::pin(async move { Ok(response) })
+    }
+
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        self.state().sleeps.push(duration);
+        Box::pin(async {})
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl crate::blocking::Transport for FixtureTransport {
+    fn send(&self, request: Request) -> Result<Response, crate::transport::TransportError> {
+        Ok(self.respond(request))
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.state().sleeps.push(duration);
+    }
+}
+
+/// Gets the fixture of the longest route matching the request.
+fn fixture(
+    method: Method,
+    path: &str,
+) -> Option<&'static (Method, &'static str, u16, &'static str)> {
+    FIXTURES
+        .iter()
+        .filter(|(m, route, ..)| *m == method && matches(route, path))
+        .max_by_key(|(_, route, ..)| segments(route))
+}
+
+fn segments(route: &str) -> usize {
+    route.split('/').count()
+}
+
+/// Determines whether the route matches the end of the path, segment by segment.
+fn matches(route: &str, path: &str) -> bool {
+    let mut path = path.trim_matches('/').split('/').rev();
+    route.split('/').rev().all(|expected| {
+        path.next()
+            .is_some_and(|s| expected == "*" || expected == s)
+    })
+}
+
+fn json_response(status: u16, body: String) -> Response {
+    Response {
+        status,
+        headers: vec![("content-type".to_string(), "application/json".to_string())],
+        body: body.into_bytes(),
+    }
+}
+
+/// Gets a body resembling the one the API sends with the status.
+fn status_body(status: u16) -> String {
+    match status {
+        202 => r#"{"data":{"is_up_to_date":false,"percent_calculated":50}}"#.to_string(),
+        401 => r#"{"errors":["Unauthorized."]}"#.to_string(),
+        403 => r#"{"errors":["Forbidden."]}"#.to_string(),
+        404 => r#"{"errors":["Not found."]}"#.to_string(),
+        429 => r#"{"errors":["Too many requests."]}"#.to_string(),
+        _ => format!(r#"{{"errors":["Status {status}."]}}"#),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::heartbeats::NewHeartbeat;
+    use crate::oauth::OAuthClient;
+    use crate::*;
+    use std::collections::HashSet;
+    use std::error::Error;
+
+    fn client(transport: &FixtureTransport) -> WakaTimeClient {
+        WakaTimeClientBuilder::new_with_api_key("waka_test")
+            .with_transport(transport.clone())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn fixtures_deserialize_into_their_models() -> Result<(), Box<dyn Error>> {
+        let transport = FixtureTransport::new();
+        let client = client(&transport);
+        let heartbeat = NewHeartbeat {
+            entity: "src/lib.rs".to_string(),
+            r#type: "file".to_string(),
+            time: 1_672_531_200.0,
+            ..Default::default()
+        };
+
+        client
+            .all_time_since_today(AllTimesSinceTodayOptions::default())
+            .await?;
+        client
+            .commit("waka-rs", "abc", CommitOptions::default())
+            .await?;
+        client.commits("waka-rs", CommitsOptions::default()).await?;
+        client
+            .durations("2023-01-01", DurationsOptions::default())
+            .await?;
+        client.goals().await?;
+        client.goal("goal-id").await?;
+        client.heartbeats("2023-01-01").await?;
+        client.send_heartbeat(&heartbeat).await?;
+        client.send_heartbeats_bulk(&[heartbeat]).await?;
+        client.leaders(LeadersOptions::default()).await?;
+        client.machine_names().await?;
+        client.orgs().await?;
+        client.org_dashboards("org").await?;
+        client
+            .org_dashboard_member_durations(
+                "org",
+                "dashboard",
+                "member",
+                "2023-01-01",
+                DurationsOptions::default(),
+            )
+            .await?;
+        client
+            .org_dashboard_member_summaries(
+                "org",
+                "dashboard",
+                "member",
+                "2023-01-01",
+                "2023-01-07",
+                SummariesOptions::default(),
+            )
+            .await?;
+        client
+            .org_dashboard_members("org", "dashboard", None)
+            .await?;
+        client.private_leaderboards().await?;
+        client.private_leaderboard_leaders("board", None).await?;
+        client.projects(ProjectsOptions::default()).await?;
+        client
+            .stats(StatsRange::Last7Days, StatsOptions::default())
+            .await?;
+        client.status_bar_today().await?;
+        client
+            .summaries("2023-01-01", "2023-01-07", SummariesOptions::default())
+            .await?;
+        client.user().await?;
+        client.user_agents().await?;
+
+        let oauth = OAuthClient::new("id", "secret", "https://example.com/callback")?
+            .with_transport(transport.clone());
+        oauth.exchange_code("code").await?;
+        oauth.revoke("token").await?;
+
+        let served = transport
+            .requests()
+            .iter()
+            .filter_map(|r| fixture(r.method, r.path()))
+            .map(|(method, route, ..)| (method.as_str(), *route))
+            .collect::<HashSet<_>>();
+        assert_eq!(served.len(), FIXTURES.len());
+        Ok(())
+    }
+
+    #[test]
+    fn longest_matching_fixture_is_used() {
+        let route = |method, path| fixture(method, path).map(|(_, route, ..)| *route);
+        assert_eq!(route(Method::Get, "/api/v1/users/current"), Some("users/*"));
+        assert_eq!(
+            route(Method::Get, "/api/v1/users/current/goals/abc"),
+            Some("users/*/goals/*")
+        );
+        assert_eq!(
+            route(Method::Post, "/api/v1/users/current/heartbeats"),
+            Some("users/*/heartbeats")
+        );
+        assert_eq!(route(Method::Post, "/api/v1/users/current/goals"), None);
+        assert_eq!(route(Method::Get, "/api/v1/unknown"), None);
+    }
+
+    #[test]
+    fn routes_match_the_end_of_the_path() {
+        assert!(matches("users/*/goals", "/api/v1/users/current/goals"));
+        assert!(matches("goals", "/api/v1/users/current/goals"));
+        assert!(matches("*", "/api/v1/users/current/goals"));
+        assert!(!matches("users/*/goals", "/api/v1/users/current/goals/abc"));
+        assert!(!matches(
+            "api/v1/users/*/goals/*",
+            "/users/current/goals/abc"
+        ));
+    }
+
+    #[tokio::test]
+    async fn custom_routes_take_precedence_over_fixtures() {
+        let transport = FixtureTransport::new();
+        let client = client(&transport);
+
+        transport.respond_with_status(Method::Get, "goals", 404);
+        assert!(matches!(client.goals().await, Err(ApiError::NotFound(_))));
+        assert!(client.goal("goal-id").await.is_ok());
+
+        transport.respond_with_status(Method::Get, "*", 500);
+        assert!(matches!(
+            client.goal("goal-id").await,
+            Err(ApiError::ServerError(500, _))
+        ));
+        assert!(matches!(
+            client.goals().await,
+            Err(ApiError::ServerError(500, _))
+        ));
+        assert!(client.private_leaderboards().await.is_err());
+
+        transport.respond_with_status(Method::Get, "users/*/goals", 403);
+        assert!(matches!(client.goals().await, Err(ApiError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn retries_are_recorded_instead_of_waited_for() {
+        let transport = FixtureTransport::new();
+        let client = WakaTimeClientBuilder::new_with_api_key("waka_test")
+            .with_transport(transport.clone())
+            .with_retry_policy(RetryPolicy {
+                initial_backoff: Duration::from_secs(10),
+                max_backoff: Duration::from_secs(60),
+                jitter: false,
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+
+        transport.enqueue_status(429);
+        transport.enqueue_status(503);
+        assert!(client.goals().await.is_ok());
+        assert_eq!(transport.requests().len(), 3);
+        assert_eq!(
+            transport.sleeps(),
+            [Duration::from_secs(10), Duration::from_secs(20)]
+        );
+    }
+
+    #[tokio::test]
+    async fn queued_responses_take_precedence_over_routes() {
+        let transport = FixtureTransport::new();
+        let client = client(&transport);
+
+        transport.respond_with_status(Method::Get, "goals", 404);
+        transport.enqueue_status(401);
+        assert!(matches!(
+            client.goals().await,
+            Err(ApiError::Unauthorized(_))
+        ));
+        assert!(matches!(client.goals().await, Err(ApiError::NotFound(_))));
+    }
+}
@@ -0,0 +1,288 @@
+//! The HTTP layer used by the clients.
+//!
+//! A [`Transport`] turns a [`Request`] into a [`Response`]. By default, requests are sent
+//! using [`ReqwestTransport`]; a custom transport can be provided using
+//! [`WakaTimeClientBuilder::with_transport`](crate::WakaTimeClientBuilder::with_transport),
+//! e.g. to add instrumentation or to answer requests from memory in tests.
+//!
+//! The transport also provides the timer the client waits with between retries and while
+//! polling. By default, it requires a [Tokio](https://tokio.rs) runtime; to use the client
+//! on another executor, provide a transport that overrides [`Transport::sleep`].
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// The HTTP method of a request.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+impl Method {
+    /// Returns the name of the method, e.g. `GET`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+        }
+    }
+}
+
+/// An HTTP request to the API.
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: Method,
+    /// The absolute URL, including the query string.
+    pub url: String,
+    /// The headers as name and value pairs.
+    pub headers: Vec<(String, String)>,
+    /// The body, if any.
+    pub body: Option<Vec<u8>>,
+}
+
+impl Request {
+    /// Gets the value of the first header with the given name, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// Gets the path of the URL, e.g. `/api/v1/users/current/stats/last_7_days`.
+    pub fn path(&self) -> &str {
+        let url = self.url.split(['?', '#']).next().unwrap_or_default();
+        let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+        without_scheme
+            .find('/')
+            .map_or("/", |start| &without_scheme[start..])
+    }
+
+    /// Gets the query string of the URL without the leading `?`, if any.
+    pub fn query(&self) -> Option<&str> {
+        let (_, query) = self.url.split_once('?')?;
+        Some(query.split('#').next().unwrap_or_default())
+    }
+}
+
+/// An HTTP response from the API.
+#[derive(Debug, Clone)]
+pub struct Response {
+    /// The status code, e.g. `200`.
+    pub status: u16,
+    /// The headers as name and value pairs.
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    /// Gets the value of the first header with the given name, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+/// The future returned by [`Transport::send`].
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Response, TransportError>> + Send + 'a>>;
+
+/// The future returned by [`Transport::sleep`].
+pub type SleepFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Sends requests to the API.
+pub trait Transport: Send + Sync {
+    /// Sends the request, returning the response regardless of its status code.
+    fn send(&self, request: Request) -> TransportFuture<'_>;
+
+    /// Waits for the given time, e.g. before retrying a request or polling an endpoint
+    /// whose data is not up to date.
+    ///
+    /// The default implementation uses the timer of the Tokio runtime and panics outside
+    /// of one; transports used with other executors should override it.
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// The kind of a [`TransportError`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TransportErrorKind {
+    /// The connection could not be established.
+    Connect,
+    /// The request timed out.
+    Timeout,
+    /// Any other failure.
+    Other,
+}
+
+/// A request could not be sent or its response could not be received.
+#[derive(Debug)]
+pub struct TransportError {
+    kind: TransportErrorKind,
+    source: Box<dyn Error + Send + Sync>,
+}
+
+impl TransportError {
+    pub fn new<E>(kind: TransportErrorKind, source: E) -> Self
+    where
+        E: Into<Box<dyn Error + Send + Sync>>,
+    {
+        Self {
+            kind,
+            source: source.into(),
+        }
+    }
+
+    pub fn kind(&self) -> TransportErrorKind {
+        self.kind
+    }
+
+    /// Indicates whether the request may succeed when retried.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.kind,
+            TransportErrorKind::Connect | TransportErrorKind::Timeout
+        )
+    }
+
+    pub(crate) fn from_reqwest(error: reqwest::Error) -> Self {
+        let kind = if error.is_connect() {
+            TransportErrorKind::Connect
+        } else if error.is_timeout() {
+            TransportErrorKind::Timeout
+        } else {
+            TransportErrorKind::Other
+        };
+        Self::new(kind, error)
+    }
+}
+
+impl Display for TransportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{source}", source = self.source)
+    }
+}
+
+impl Error for TransportError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+/// The default [`Transport`], based on [`reqwest`].
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new() -> Result<Self, TransportError> {
+        let client = reqwest::Client::builder()
+            .build()
+            .map_err(TransportError::from_reqwest)?;
+        Ok(Self { client })
+    }
+
+    /// Uses a preconfigured client, e.g. one with a proxy or custom timeouts.
+    pub fn from_client(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: Request) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let mut builder = self
+                .client
+                .request(reqwest_method(request.method), &request.url);
+            for (name, value) in &request.headers {
+                builder = builder.header(name, value);
+            }
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let response = builder.send().await.map_err(TransportError::from_reqwest)?;
+            let status = response.status().as_u16();
+            let headers = reqwest_headers(response.headers());
+            let body = response
+                .bytes()
+                .await
+                .map_err(TransportError::from_reqwest)?;
+
+            Ok(Response {
+                status,
+                headers,
+                body: body.to_vec(),
+            })
+        })
+    }
+}
+
+pub(crate) fn reqwest_method(method: Method) -> reqwest::Method {
+    match method {
+        Method::Get => reqwest::Method::GET,
+        Method::Post => reqwest::Method::POST,
+    }
+}
+
+pub(crate) fn reqwest_headers(headers: &reqwest::header::HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(url: &str) -> Request {
+        Request {
+            method: Method::Get,
+            url: url.to_string(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    #[test]
+    fn path_excludes_the_origin_and_query() {
+        assert_eq!(
+            request("https://wakatime.com/api/v1/users/current/stats/last_7_days?timeout=15")
+                .path(),
+            "/api/v1/users/current/stats/last_7_days"
+        );
+        assert_eq!(
+            request("http://localhost:3000/api/v1/users/current#top").path(),
+            "/api/v1/users/current"
+        );
+        assert_eq!(request("http://localhost:3000").path(), "/");
+        assert_eq!(request("http://localhost:3000?page=2").path(), "/");
+    }
+
+    #[test]
+    fn query_excludes_the_leading_question_mark_and_fragment() {
+        assert_eq!(
+            request("https://wakatime.com/api/v1/leaders?language=Rust&page=2").query(),
+            Some("language=Rust&page=2")
+        );
+        assert_eq!(
+            request("https://wakatime.com/api/v1/leaders?page=2#top").query(),
+            Some("page=2")
+        );
+        assert_eq!(
+            request("https://wakatime.com/api/v1/leaders?").query(),
+            Some("")
+        );
+        assert_eq!(request("https://wakatime.com/api/v1/leaders").query(), None);
+    }
+}